@@ -0,0 +1,236 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use common::{Day, InputArguments, ParseError, Part, ThreadArguments};
+
+#[derive(Args)]
+pub struct BenchArguments {
+    /// Only benchmark this part (1 or 2); parsing is always timed
+    #[arg(long)]
+    part: Option<Part>,
+
+    #[command(flatten)]
+    pub input: InputArguments,
+
+    #[command(flatten)]
+    threads: ThreadArguments,
+
+    /// How often to run every phase; the median time is reported
+    #[arg(long, default_value_t = 5)]
+    runs: usize,
+
+    /// Write the timings as JSON to this file
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compare the timings with a file written earlier with --save
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown compared to the baseline, in percent, from which a timing is a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// Runs one phase on the input.
+type Run<'a> = Box<dyn Fn() -> Result<(), ParseError> + 'a>;
+
+/// The timings of a benchmark run, as stored in JSON.
+#[derive(Serialize, Deserialize, Default)]
+struct Report {
+    timings: Vec<Timing>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Timing {
+    day: u8,
+    phase: Phase,
+    nanos: u64,
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Phase {
+    Parse,
+    One,
+    Two,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::One => "one",
+            Phase::Two => "two",
+        })
+    }
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::One,
+            Part::Two => Phase::Two,
+        }
+    }
+}
+
+/// Times the days, and returns the exit code: 1 when there is any regression, else 0.
+pub fn bench<'a>(days: impl Iterator<Item = &'a Day>, arguments: &BenchArguments) -> i32 {
+    if let Err(error) = arguments.threads.configure() {
+        eprintln!("{error}");
+        return 2;
+    }
+
+    let baseline = match &arguments.baseline {
+        None => None,
+        Some(path) => match read(path) {
+            Ok(baseline) => Some(baseline),
+            Err(error) => {
+                eprintln!("can't use baseline {}: {error}", path.display());
+                return 2;
+            }
+        },
+    };
+
+    println!("day  phase      median    baseline   change");
+
+    let mut report = Report::default();
+    let mut regressions = 0;
+
+    days.for_each(|day| {
+        let input = match arguments.input.load(day) {
+            Ok(input) => input.contents,
+            Err(error) => {
+                eprintln!("day {:02}: {error}", day.number);
+                return;
+            }
+        };
+
+        // the parts are timed on a model parsed once, so their timings don't include parsing
+        let model = match (day.model)(&input) {
+            Ok(model) => model,
+            Err(error) => {
+                eprintln!("{}", error.locate(day.number, &input));
+                return;
+            }
+        };
+
+        let mut phases: Vec<(Phase, Run)> =
+            vec![(Phase::Parse, Box::new(|| (day.model)(&input).map(drop)))];
+        parts(arguments.part).into_iter().for_each(|part| {
+            let model = &model;
+            phases.push((
+                Phase::from(part),
+                Box::new(move || model.solve(part).map(drop)),
+            ));
+        });
+
+        phases.iter().for_each(|(phase, run)| {
+            let timing = match median(arguments.runs, run) {
+                Ok(median) => Timing {
+                    day: day.number,
+                    phase: *phase,
+                    nanos: median.as_nanos() as u64,
+                },
+                Err(error) => {
+                    eprintln!("{}", error.locate(day.number, &input));
+                    return;
+                }
+            };
+            let previous = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.find(&timing));
+
+            print!(
+                " {:02}  {phase:<5}  {:>10}",
+                day.number,
+                duration(timing.nanos)
+            );
+            match previous {
+                None => println!(),
+                Some(previous) => {
+                    let change = (timing.nanos as f64 / previous.nanos as f64 - 1.0) * 100.0;
+                    let regression = change > arguments.threshold;
+                    if regression {
+                        regressions += 1;
+                    }
+
+                    println!(
+                        "  {:>10}  {change:>+6.1}%{}",
+                        duration(previous.nanos),
+                        match regression {
+                            true => "  REGRESSION",
+                            false => "",
+                        }
+                    );
+                }
+            }
+
+            report.timings.push(timing);
+        });
+    });
+
+    if let Some(path) = &arguments.save {
+        if let Err(error) = write(path, &report) {
+            eprintln!("can't save timings to {}: {error}", path.display());
+            return 2;
+        }
+    }
+
+    match regressions {
+        0 => 0,
+        _ => {
+            println!("{regressions} regression(s) over {}%", arguments.threshold);
+            1
+        }
+    }
+}
+
+impl Report {
+    fn find(&self, timing: &Timing) -> Option<&Timing> {
+        self.timings
+            .iter()
+            .find(|other| other.day == timing.day && other.phase == timing.phase)
+    }
+}
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        None => Part::all().to_vec(),
+        Some(part) => vec![part],
+    }
+}
+
+fn median(runs: usize, run: impl Fn() -> Result<(), ParseError>) -> Result<Duration, ParseError> {
+    let mut durations: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run()?;
+            Ok(start.elapsed())
+        })
+        .collect::<Result<_, _>>()?;
+    durations.sort();
+
+    Ok(durations[durations.len() / 2])
+}
+
+fn duration(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+fn read(path: &PathBuf) -> Result<Report, String> {
+    let contents = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+
+    serde_json::from_str(&contents).map_err(|error| error.to_string())
+}
+
+fn write(path: &PathBuf, report: &Report) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(report).map_err(|error| error.to_string())?;
+
+    std::fs::write(path, contents + "\n").map_err(|error| error.to_string())
+}
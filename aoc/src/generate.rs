@@ -0,0 +1,45 @@
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+
+use common::generate::Random;
+use common::Day;
+
+#[derive(Args)]
+pub struct GenerateArguments {
+    /// How big the input is, e.g. the number of lines; what it counts differs per day
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// The seed to generate the input from, to reproduce it; random when not given
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Writes a random input for a day to stdout, and returns the exit code: 0 when the day has a
+/// generator, else 1. The seed is written to stderr.
+pub fn generate(day: &Day, arguments: &GenerateArguments) -> i32 {
+    let Some(generate) = day.generate else {
+        eprintln!("day {:02} has no input generator", day.number);
+        return 1;
+    };
+
+    let seed = arguments.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    eprintln!("seed {seed}");
+
+    let input = generate(&mut Random::new(seed), arguments.size);
+
+    match std::io::stdout().lock().write_all(input.as_bytes()) {
+        Ok(()) => 0,
+        Err(error) => {
+            eprintln!("{error}");
+            1
+        }
+    }
+}
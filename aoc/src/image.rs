@@ -0,0 +1,190 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use common::visual::{Picture, Pixel};
+
+/// The size of a cell in thirds: characters are drawn as shapes on a 3x3 raster.
+const THIRDS: usize = 3;
+/// Pixels per third of a cell in a PNG.
+const PNG_SCALE: usize = 2;
+
+const BACKGROUND: [u8; 3] = [24, 24, 24];
+/// For pixels without a colour.
+const FOREGROUND: [u8; 3] = [204, 204, 204];
+
+/// A rectangle in thirds of a cell: x, y, width and height.
+type Shape = (usize, usize, usize, usize);
+
+/// Saves a picture as SVG or PNG, by the extension of the path.
+pub fn save(picture: &Picture, path: &Path) -> Result<(), String> {
+    let contents = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => svg(picture).into_bytes(),
+        Some("png") => png(picture)?,
+        _ => return Err("expected a .svg or .png file".to_string()),
+    };
+
+    std::fs::write(path, contents).map_err(|error| error.to_string())
+}
+
+/// The rectangles to draw a character with: lines for the box drawing characters as used for
+/// pipes, a dot for dots and a full cell for anything else but a space.
+fn shapes(char: char) -> Vec<Shape> {
+    let (up, right, down, left) = ((1, 0, 1, 2), (1, 1, 2, 1), (1, 1, 1, 2), (0, 1, 2, 1));
+
+    match char {
+        ' ' => vec![],
+        '.' | '•' | '·' => vec![(1, 1, 1, 1)],
+        '│' => vec![up, down],
+        '─' => vec![left, right],
+        '└' => vec![up, right],
+        '┘' => vec![up, left],
+        '┐' => vec![down, left],
+        '┌' => vec![down, right],
+        _ => vec![(0, 0, THIRDS, THIRDS)],
+    }
+}
+
+fn colour(pixel: &Pixel) -> [u8; 3] {
+    pixel
+        .colour
+        .map(|colour| colour.rgb())
+        .unwrap_or(FOREGROUND)
+}
+
+/// The rectangles of all cells, in thirds, with their colours.
+fn rectangles(picture: &Picture) -> impl Iterator<Item = (Shape, [u8; 3])> + '_ {
+    picture.rows.iter().enumerate().flat_map(|(y, row)| {
+        row.iter().enumerate().flat_map(move |(x, pixel)| {
+            shapes(pixel.char)
+                .into_iter()
+                .map(move |(dx, dy, width, height)| {
+                    (
+                        (x * THIRDS + dx, y * THIRDS + dy, width, height),
+                        colour(pixel),
+                    )
+                })
+        })
+    })
+}
+
+fn svg(picture: &Picture) -> String {
+    let width = picture.width() * THIRDS;
+    let height = picture.height() * THIRDS;
+    // room for a line of text below the cells
+    let caption = 2 * THIRDS;
+    let mut svg = String::new();
+
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {}" width="{}" height="{}" shape-rendering="crispEdges">"#,
+        height + caption,
+        width * 2,
+        (height + caption) * 2,
+    )
+    .expect("writing to a string");
+    writeln!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        hex(BACKGROUND)
+    )
+    .expect("writing to a string");
+
+    rectangles(picture).for_each(|((x, y, width, height), colour)| {
+        writeln!(
+            svg,
+            r#"<rect x="{x}" y="{y}" width="{width}" height="{height}" fill="{}"/>"#,
+            hex(colour)
+        )
+        .expect("writing to a string")
+    });
+
+    writeln!(
+        svg,
+        r#"<text x="0" y="{}" font-family="monospace" font-size="{THIRDS}" fill="{}">{}</text>"#,
+        height + caption - 1,
+        hex(FOREGROUND),
+        escape(&picture.caption)
+    )
+    .expect("writing to a string");
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+fn hex([red, green, blue]: [u8; 3]) -> String {
+    format!("#{red:02x}{green:02x}{blue:02x}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The cells only, without the caption: there is no font to draw it with.
+fn png(picture: &Picture) -> Result<Vec<u8>, String> {
+    let width = picture.width() * THIRDS * PNG_SCALE;
+    let height = picture.height() * THIRDS * PNG_SCALE;
+    let mut data: Vec<u8> = BACKGROUND
+        .iter()
+        .copied()
+        .cycle()
+        .take(width * height * 3)
+        .collect();
+
+    rectangles(picture).for_each(|((x, y, shape_width, shape_height), colour)| {
+        (y * PNG_SCALE..(y + shape_height) * PNG_SCALE).for_each(|row| {
+            (x * PNG_SCALE..(x + shape_width) * PNG_SCALE).for_each(|column| {
+                let index = (row * width + column) * 3;
+                data[index..index + 3].copy_from_slice(&colour);
+            })
+        })
+    });
+
+    let mut bytes = vec![];
+    let mut encoder = png::Encoder::new(&mut bytes, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(|error| error.to_string())?;
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::visual::Colour;
+
+    use super::*;
+
+    fn example() -> Picture {
+        Picture {
+            rows: vec![
+                vec![Pixel::coloured('┌', Colour::Green), Pixel::new('#')],
+                vec![Pixel::new(' '), Pixel::new('.')],
+            ],
+            caption: "a < b".to_string(),
+        }
+    }
+
+    #[test]
+    fn svg_rectangles() {
+        let svg = svg(&example());
+
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 1 + 2 + 1 + 1);
+        assert!(svg.contains(r##"<rect x="3" y="0" width="3" height="3" fill="#cccccc"/>"##));
+        assert!(svg.contains("a &lt; b</text>"));
+    }
+
+    #[test]
+    fn png_header() {
+        let png = png(&example()).expect("png");
+
+        assert_eq!(&png[1..4], b"PNG");
+        // width and height in the IHDR chunk
+        assert_eq!(&png[16..24], &[0, 0, 0, 12, 0, 0, 0, 12]);
+    }
+}
@@ -0,0 +1,150 @@
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+
+use common::{Day, Format, InputArguments, RunArguments, Runner};
+
+use crate::bench::BenchArguments;
+use crate::generate::GenerateArguments;
+use crate::selection::Selection;
+use crate::visualize::VisualizeArguments;
+
+mod bench;
+mod generate;
+mod image;
+mod selection;
+mod visualize;
+
+const DAYS: [&Day; 24] = [
+    &day01::DAY,
+    &day02::DAY,
+    &day03::DAY,
+    &day04::DAY,
+    &day05::DAY,
+    &day06::DAY,
+    &day07::DAY,
+    &day08::DAY,
+    &day09::DAY,
+    &day10::DAY,
+    &day11::DAY,
+    &day12::DAY,
+    &day13::DAY,
+    &day14::DAY,
+    &day15::DAY,
+    &day16::DAY,
+    &day17::DAY,
+    &day18::DAY,
+    &day19::DAY,
+    &day20::DAY,
+    &day21::DAY,
+    &day22::DAY,
+    &day23::DAY,
+    &day24::DAY,
+];
+
+#[derive(Parser)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the puzzles of the selected days
+    Run {
+        /// `all`, a single day (`17`) or a range of days (`3..7`)
+        days: Selection,
+
+        #[command(flatten)]
+        arguments: RunArguments,
+    },
+    /// Time parsing and both parts of the selected days
+    Bench {
+        /// `all`, a single day (`17`) or a range of days (`3..7`)
+        days: Selection,
+
+        #[command(flatten)]
+        arguments: BenchArguments,
+    },
+    /// Draw the puzzle of a day in the terminal, for the grid days (10, 14, 16, 17, 21 and 23)
+    Visualize {
+        /// The day to draw
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=24))]
+        day: u8,
+
+        #[command(flatten)]
+        arguments: VisualizeArguments,
+    },
+    /// Print a random input for a day, for days 07, 10, 12, 19, 20 and 22
+    Generate {
+        /// The day to generate an input for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=24))]
+        day: u8,
+
+        #[command(flatten)]
+        arguments: GenerateArguments,
+    },
+}
+
+fn main() {
+    match Arguments::parse().command {
+        Command::Run { days, arguments } => {
+            check_input(&days, &arguments.input);
+
+            let mut runner = Runner::new(arguments);
+
+            selected(&days).for_each(|day| {
+                if runner.format() == Format::Text {
+                    println!("day {:02}", day.number);
+                }
+                runner.run(day);
+            });
+
+            std::process::exit(runner.finish())
+        }
+        Command::Bench { days, arguments } => {
+            check_input(&days, &arguments.input);
+
+            std::process::exit(bench::bench(selected(&days), &arguments))
+        }
+        Command::Visualize { day, arguments } => {
+            let day = DAYS[day as usize - 1];
+
+            std::process::exit(visualize::visualize(day, &arguments))
+        }
+        Command::Generate { day, arguments } => {
+            let day = DAYS[day as usize - 1];
+
+            std::process::exit(generate::generate(day, &arguments))
+        }
+    }
+}
+
+fn selected(days: &Selection) -> impl Iterator<Item = &'static Day> + '_ {
+    DAYS.into_iter().filter(|day| days.contains(day.number))
+}
+
+/// Exits when a single input file is given for multiple days.
+fn check_input(days: &Selection, input: &InputArguments) {
+    if input.input.is_some() && !days.is_single() {
+        Arguments::command()
+            .error(ErrorKind::ArgumentConflict, "--input needs a single day")
+            .exit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncated_examples() {
+        DAYS.into_iter()
+            .for_each(common::testing::truncated_examples);
+    }
+
+    #[test]
+    #[ignore = "slow, run with cargo test --release -- --ignored"]
+    fn truncated_inputs() {
+        DAYS.into_iter().for_each(common::testing::truncated_input);
+    }
+}
@@ -0,0 +1,56 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const FIRST_DAY: u8 = 1;
+const LAST_DAY: u8 = 24;
+
+/// The days to run: `all`, a single day (`17`) or a range of days (`3..7` or `3-7`).
+#[derive(Debug, Clone)]
+pub struct Selection {
+    days: RangeInclusive<u8>,
+}
+
+impl Selection {
+    pub fn contains(&self, day: u8) -> bool {
+        self.days.contains(&day)
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.days.start() == self.days.end()
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days = match s {
+            "all" => FIRST_DAY..=LAST_DAY,
+            _ => match s
+                .split_once("..=")
+                .or(s.split_once(".."))
+                .or(s.split_once('-'))
+            {
+                None => {
+                    let day = parse_day(s)?;
+                    day..=day
+                }
+                Some((from, to)) => parse_day(from)?..=parse_day(to)?,
+            },
+        };
+
+        match days.is_empty() {
+            true => Err(format!("no days in {s}")),
+            false => Ok(Self { days }),
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "unexpected day {s}, expected {FIRST_DAY} to {LAST_DAY}"
+        )),
+    }
+}
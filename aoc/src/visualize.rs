@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use common::visual::{Screen, VisualArguments};
+use common::{Day, InputArguments, Part};
+
+use crate::image;
+
+#[derive(Args)]
+pub struct VisualizeArguments {
+    /// The part to draw (1 or 2)
+    #[arg(long, default_value = "1")]
+    part: Part,
+
+    #[command(flatten)]
+    pub input: InputArguments,
+
+    #[command(flatten)]
+    visual: VisualArguments,
+
+    /// Also save the last frame as an image, in SVG or PNG by the extension
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// Draws a day in the terminal, and returns the exit code: 0 when it could be drawn, else 1.
+pub fn visualize(day: &Day, arguments: &VisualizeArguments) -> i32 {
+    let Some(visualize) = day.visualize else {
+        eprintln!("day {:02} can't be visualized", day.number);
+        return 1;
+    };
+
+    let input = match arguments.input.load(day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return 1;
+        }
+    };
+
+    let mut screen = Screen::terminal(arguments.visual.clone());
+
+    match visualize(&input.contents, arguments.part, &mut screen) {
+        Ok(()) => {
+            let saved = match (&arguments.output, screen.picture()) {
+                (Some(path), Some(picture)) => image::save(picture, path)
+                    .map_err(|error| eprintln!("can't save {}: {error}", path.display())),
+                _ => Ok(()),
+            };
+            screen.finish();
+
+            match saved {
+                Ok(()) => 0,
+                Err(()) => 1,
+            }
+        }
+        Err(error) => {
+            eprintln!("{}", error.locate(day.number, &input.contents));
+            1
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::day::Part;
+
+const ANSWERS: &str = include_str!("../../answers.txt");
+
+/// The expected answers per day, part and input, as read from `answers.txt`.
+#[derive(Default)]
+pub struct Answers {
+    expectations: HashMap<Key, Expectation>,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct Key {
+    day: u8,
+    part: Part,
+    input: String,
+}
+
+/// Everything known about the answer for one input: the answer itself and/or answers that were
+/// submitted before and turned out to be wrong.
+#[derive(Default)]
+struct Expectation {
+    answer: Option<String>,
+    too_high: Vec<i128>,
+    too_low: Vec<i128>,
+    wrong: Vec<String>,
+}
+
+impl Answers {
+    /// The answers that come with the repository.
+    pub fn builtin() -> Self {
+        Self::from_str(ANSWERS).expect("valid answers.txt")
+    }
+
+    /// Adds the answers of `other`, e.g. those of a teammate.
+    pub fn extend(&mut self, other: Answers) {
+        other.expectations.into_iter().for_each(|(key, other)| {
+            let expectation = self.expectations.entry(key).or_default();
+            if other.answer.is_some() {
+                expectation.answer = other.answer;
+            }
+            expectation.too_high.extend(other.too_high);
+            expectation.too_low.extend(other.too_low);
+            expectation.wrong.extend(other.wrong);
+        })
+    }
+
+    /// The correct answer, if it is known.
+    pub fn expected(&self, day: u8, part: Part, input: &str) -> Option<String> {
+        let key = Key {
+            day,
+            part,
+            input: input.to_string(),
+        };
+
+        self.expectations
+            .get(&key)
+            .and_then(|expectation| expectation.answer.clone())
+    }
+
+    pub fn verify(&self, day: u8, part: Part, input: &str, actual: &str) -> Verification {
+        let key = Key {
+            day,
+            part,
+            input: input.to_string(),
+        };
+
+        match self.expectations.get(&key) {
+            None => Verification::Unknown,
+            Some(expectation) => expectation.verify(actual),
+        }
+    }
+}
+
+impl Expectation {
+    fn verify(&self, actual: &str) -> Verification {
+        if let Some(answer) = &self.answer {
+            return match answer == actual {
+                true => Verification::Correct,
+                false => Verification::Incorrect(answer.clone()),
+            };
+        }
+
+        if self.wrong.iter().any(|wrong| wrong == actual) {
+            return Verification::KnownWrong;
+        }
+
+        match actual.parse::<i128>() {
+            Err(_) if self.too_high.is_empty() && self.too_low.is_empty() => Verification::Unknown,
+            Err(_) => Verification::Incorrect("a number".to_string()),
+            Ok(actual) => {
+                let lowest_too_high = self.too_high.iter().filter(|high| actual >= **high).min();
+                let highest_too_low = self.too_low.iter().filter(|low| actual <= **low).max();
+
+                match (lowest_too_high, highest_too_low) {
+                    (Some(high), _) => Verification::TooHigh(*high),
+                    (_, Some(low)) => Verification::TooLow(*low),
+                    (None, None) => match self.too_high.is_empty() && self.too_low.is_empty() {
+                        true => Verification::Unknown,
+                        false => Verification::Plausible,
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+
+        s.lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .try_for_each(|(line_number, line)| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [day, part, input, relation, answer] = fields[..] else {
+                    return Err(format!("line {line_number}: expected 5 fields in {line}"));
+                };
+
+                let day = day
+                    .parse()
+                    .map_err(|_| format!("line {line_number}: unexpected day {day}"))?;
+                let part = Part::from_str(part).map_err(|e| format!("line {line_number}: {e}"))?;
+                let number = || {
+                    answer.parse::<i128>().map_err(|_| {
+                        format!("line {line_number}: expected a number for {relation}")
+                    })
+                };
+
+                let expectation = answers
+                    .expectations
+                    .entry(Key {
+                        day,
+                        part,
+                        input: input.to_string(),
+                    })
+                    .or_default();
+
+                match relation {
+                    "=" => expectation.answer = Some(answer.to_string()),
+                    "<" => expectation.too_high.push(number()?),
+                    ">" => expectation.too_low.push(number()?),
+                    "!=" => expectation.wrong.push(answer.to_string()),
+                    _ => {
+                        return Err(format!(
+                            "line {line_number}: unexpected relation {relation}"
+                        ))
+                    }
+                }
+
+                Ok(())
+            })?;
+
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verification {
+    Correct,
+    Incorrect(String),
+    TooHigh(i128),
+    TooLow(i128),
+    KnownWrong,
+    /// Only known wrong answers are available, and this answer is not one of them.
+    Plausible,
+    Unknown,
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Verification::Incorrect(_)
+                | Verification::TooHigh(_)
+                | Verification::TooLow(_)
+                | Verification::KnownWrong
+        )
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verification::Correct => f.write_str("OK"),
+            Verification::Incorrect(expected) => {
+                f.write_fmt(format_args!("ERROR: expected {expected}"))
+            }
+            Verification::TooHigh(high) => {
+                f.write_fmt(format_args!("ERROR: too high, {high} already was"))
+            }
+            Verification::TooLow(low) => {
+                f.write_fmt(format_args!("ERROR: too low, {low} already was"))
+            }
+            Verification::KnownWrong => f.write_str("ERROR: known to be wrong"),
+            Verification::Plausible => f.write_str("UNVERIFIED: within the known bounds"),
+            Verification::Unknown => f.write_str("UNVERIFIED: no expected answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify() {
+        let answers = Answers::from_str(
+            "# comment\n\n01 one input = 42\n01 two input < 100\n01 two input > 10\n01 two input != 50\n",
+        )
+        .expect("valid answers");
+
+        assert_eq!(
+            answers.verify(1, Part::One, "input", "42"),
+            Verification::Correct
+        );
+        assert_eq!(
+            answers.verify(1, Part::One, "input", "43"),
+            Verification::Incorrect("42".to_string())
+        );
+        assert_eq!(
+            answers.verify(1, Part::Two, "input", "100"),
+            Verification::TooHigh(100)
+        );
+        assert_eq!(
+            answers.verify(1, Part::Two, "input", "9"),
+            Verification::TooLow(10)
+        );
+        assert_eq!(
+            answers.verify(1, Part::Two, "input", "50"),
+            Verification::KnownWrong
+        );
+        assert_eq!(
+            answers.verify(1, Part::Two, "input", "51"),
+            Verification::Plausible
+        );
+        assert_eq!(
+            answers.verify(2, Part::One, "input", "1"),
+            Verification::Unknown
+        );
+        assert_eq!(
+            answers.expected(1, Part::One, "input"),
+            Some("42".to_string())
+        );
+        assert_eq!(answers.expected(1, Part::Two, "input"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Answers::from_str("01 one input =").err(),
+            Some("line 1: expected 5 fields in 01 one input =".to_string())
+        );
+        assert_eq!(
+            Answers::from_str("\n01 three input = 1").err(),
+            Some("line 2: unexpected part three, expected 1 or 2".to_string())
+        );
+        assert_eq!(
+            Answers::from_str("01 one input ~ 1").err(),
+            Some("line 1: unexpected relation ~".to_string())
+        );
+    }
+
+    #[test]
+    fn builtin() {
+        Answers::builtin();
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::day::Part::{One, Two};
+use crate::generate::Random;
+use crate::parse::ParseError;
+use crate::visual::Screen;
+
+/// A puzzle of a single day, as registered by the day's library.
+pub struct Day {
+    pub number: u8,
+    /// The puzzle input compiled into the binary, if any.
+    pub input: Option<&'static str>,
+    /// Parses the input once into a model that solves both parts, so parsing can be timed apart
+    /// from solving.
+    pub model: Prepare,
+    /// Draws the puzzle, for the days that can.
+    pub visualize: Option<Visualize>,
+    /// Generates a random valid input, for the days that can.
+    pub generate: Option<Generate>,
+    pub one: Solution,
+    pub two: Solution,
+}
+
+impl Day {
+    pub fn solution(&self, part: Part) -> &Solution {
+        match part {
+            One => &self.one,
+            Two => &self.two,
+        }
+    }
+}
+
+/// A day's puzzle as a library: the input is parsed into a model once, and both parts are solved
+/// from it with typed answers. Each day implements it, and registers its [Day] through it.
+pub trait Solver {
+    /// The model of the puzzle input.
+    type Parsed;
+    /// The answer of part one.
+    type One: Display;
+    /// The answer of part two.
+    type Two: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    /// Fails when the model can't answer this part, e.g. because the input lacks what only this
+    /// part needs.
+    fn part_one(parsed: &Self::Parsed) -> Result<Self::One, ParseError>;
+
+    /// Fails when the model can't answer this part, e.g. because the input lacks what only this
+    /// part needs.
+    fn part_two(parsed: &Self::Parsed) -> Result<Self::Two, ParseError>;
+
+    /// Parses the input into a model solving the parts with answers as text, as a [Prepare].
+    fn model(input: &str) -> Result<Box<dyn Model>, ParseError>
+    where
+        Self: Sized + 'static,
+        Self::Parsed: Send + Sync,
+    {
+        Ok(Box::new(Parsed::<Self>(Self::parse(input)?)))
+    }
+
+    /// Solves part one with the answer as text, as a [Solve].
+    fn one(input: &str) -> Result<String, ParseError> {
+        Ok(Self::part_one(&Self::parse(input)?)?.to_string())
+    }
+
+    /// Solves part two with the answer as text, as a [Solve].
+    fn two(input: &str) -> Result<String, ParseError> {
+        Ok(Self::part_two(&Self::parse(input)?)?.to_string())
+    }
+}
+
+/// A parsed input, solving both parts with the answers as text.
+pub trait Model: Send + Sync {
+    fn solve(&self, part: Part) -> Result<String, ParseError>;
+}
+
+/// The model of a [Solver], as a [Model].
+struct Parsed<S: Solver>(S::Parsed);
+
+impl<S: Solver> Model for Parsed<S>
+where
+    S::Parsed: Send + Sync,
+{
+    fn solve(&self, part: Part) -> Result<String, ParseError> {
+        match part {
+            One => Ok(S::part_one(&self.0)?.to_string()),
+            Two => Ok(S::part_two(&self.0)?.to_string()),
+        }
+    }
+}
+
+/// Parses an input into the model both parts are solved from.
+pub type Prepare = fn(&str) -> Result<Box<dyn Model>, ParseError>;
+
+/// Draws a part for an input on the screen, frame by frame.
+pub type Visualize = fn(&str, Part, &mut Screen) -> Result<(), ParseError>;
+
+/// Generates a random input of about the given size (e.g. a number of lines); what the size means
+/// differs per day.
+pub type Generate = fn(&mut Random, usize) -> String;
+
+/// Solves a part for an input; fails only when the input can't be parsed.
+pub type Solve = fn(&str) -> Result<String, ParseError>;
+
+/// One part of a puzzle: how to solve it, and the examples to check it with. The expected answers
+/// are kept in answers.txt.
+pub struct Solution {
+    pub solve: Solve,
+    pub examples: &'static [Example],
+}
+
+/// An example from the puzzle description. Some examples use different parameters than the real
+/// input (e.g. fewer steps), so they bring their own `solve`.
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub solve: Solve,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [One, Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            One => "one",
+            Two => "two",
+        })
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "one" => Ok(One),
+            "2" | "two" => Ok(Two),
+            _ => Err(format!("unexpected part {s}, expected 1 or 2")),
+        }
+    }
+}
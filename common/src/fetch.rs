@@ -0,0 +1,320 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Where the puzzle inputs come from.
+pub const SITE: &str = "https://adventofcode.com";
+
+/// How long to wait between requests to the site, also across runs.
+pub const THROTTLE: Duration = Duration::from_secs(5);
+
+/// Identifies the requests, as the site asks of tools.
+const USER_AGENT: &str = "advent-of-code-2023 input cache (via curl)";
+
+/// Gets the puzzle input of a day, from the site or a stand-in for it.
+pub trait Fetch {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+/// The site, logged in with the session cookie of a user. Requests are made with `curl`.
+pub struct Site {
+    url: String,
+    session: String,
+}
+
+impl Site {
+    pub fn new(session: &str) -> Self {
+        Self::at(SITE, session)
+    }
+
+    /// A server at another address with the same paths as the site.
+    pub fn at(url: &str, session: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+}
+
+impl Fetch for Site {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/{year}/day/{day}/input", self.url);
+
+        // the cookie goes in through stdin, so the session doesn't show in the process list
+        let config = format!(
+            "cookie = {}\n",
+            quoted(&format!("session={}", self.session))
+        );
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--config", "-"])
+            .args(["--user-agent", USER_AGENT])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(FetchError::Curl)?;
+        curl.stdin
+            .take()
+            .expect("stdin of curl")
+            .write_all(config.as_bytes())
+            .map_err(FetchError::Curl)?;
+        let output = curl.wait_with_output().map_err(FetchError::Curl)?;
+
+        match output.status.success() {
+            true => String::from_utf8(output.stdout)
+                .map_err(|_| FetchError::Failed(url, "the input isn't text".to_string())),
+            false => Err(FetchError::Failed(
+                url,
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            )),
+        }
+    }
+}
+
+/// Quotes a value for a curl config file, escaping what would end the value or the line, so any
+/// session stays a single cookie.
+fn quoted(value: &str) -> String {
+    let escaped: String = value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+
+    format!("\"{escaped}\"")
+}
+
+/// A copy of the site's inputs in a directory, as `<year>/day/<day>/input` like the paths on the
+/// site, e.g. to work offline.
+pub struct Mirror {
+    directory: PathBuf,
+}
+
+impl Mirror {
+    pub fn new(directory: &Path) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+        }
+    }
+}
+
+impl Fetch for Mirror {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let path = self
+            .directory
+            .join(year.to_string())
+            .join("day")
+            .join(day.to_string())
+            .join("input");
+
+        std::fs::read_to_string(&path).map_err(|error| FetchError::Read(path, error))
+    }
+}
+
+/// Waits before fetching until `interval` has passed since the last fetch. The time of the last
+/// fetch is kept in the `stamp` file, in nanoseconds since 1970, so runs one after the other are
+/// throttled too.
+pub struct Throttled<F> {
+    fetch: F,
+    interval: Duration,
+    stamp: PathBuf,
+}
+
+impl<F: Fetch> Throttled<F> {
+    pub fn new(fetch: F, interval: Duration, stamp: &Path) -> Self {
+        Self {
+            fetch,
+            interval,
+            stamp: stamp.to_path_buf(),
+        }
+    }
+}
+
+impl<F: Fetch> Fetch for Throttled<F> {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let since_last = std::fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos))
+            .and_then(|last| SystemTime::now().duration_since(last).ok());
+
+        if let Some(since_last) = since_last {
+            std::thread::sleep(self.interval.saturating_sub(since_last));
+        }
+
+        // stamped before fetching, so failed requests count as well
+        if let Some(directory) = self.stamp.parent() {
+            let _ = std::fs::create_dir_all(directory);
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("a time after 1970");
+        let _ = std::fs::write(&self.stamp, now.as_nanos().to_string());
+
+        self.fetch.fetch(year, day)
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Curl(std::io::Error),
+    Failed(String, String),
+    Read(PathBuf, std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Curl(error) => f.write_fmt(format_args!("can't run curl: {error}")),
+            FetchError::Failed(url, message) => {
+                f.write_fmt(format_args!("can't fetch {url}: {message}"))
+            }
+            FetchError::Read(path, error) => {
+                f.write_fmt(format_args!("can't read {}: {error}", path.display()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::time::Instant;
+
+    use crate::input::{Cache, InputError};
+
+    use super::*;
+
+    /// Stands in for the site, counting the requests.
+    struct Counting {
+        requests: Cell<usize>,
+    }
+
+    impl Fetch for Counting {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+            self.requests.set(self.requests.get() + 1);
+            Ok(format!("input of {year} day {day}\n"))
+        }
+    }
+
+    fn directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn cache_fetches_once() {
+        let directory = directory("cache");
+        let cache = Cache::new(&directory);
+        let site = Counting {
+            requests: Cell::new(0),
+        };
+
+        assert!(matches!(
+            cache.load(2023, 5, "alice", None),
+            Err(InputError::Read(_, _))
+        ));
+        (0..2).for_each(|_| {
+            assert_eq!(
+                cache.load(2023, 5, "alice", Some(&site)).unwrap(),
+                "input of 2023 day 5\n"
+            );
+        });
+        cache.load(2023, 5, "bob", Some(&site)).unwrap();
+
+        assert_eq!(site.requests.get(), 2);
+        assert!(directory.join("2023/alice/day05.txt").exists());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn throttles_fetches() {
+        let directory = directory("throttle");
+        let interval = Duration::from_millis(200);
+        let site = Throttled::new(
+            Counting {
+                requests: Cell::new(0),
+            },
+            interval,
+            &directory.join("last-fetch"),
+        );
+
+        let start = Instant::now();
+        site.fetch(2023, 1).unwrap();
+        assert!(start.elapsed() < interval);
+        site.fetch(2023, 2).unwrap();
+        assert!(start.elapsed() >= interval);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn mirrors_the_site() {
+        let directory = directory("mirror");
+        std::fs::create_dir_all(directory.join("2023/day/7")).unwrap();
+        std::fs::write(directory.join("2023/day/7/input"), "32T3K 765\n").unwrap();
+
+        let mirror = Mirror::new(&directory);
+        assert_eq!(mirror.fetch(2023, 7).unwrap(), "32T3K 765\n");
+        assert!(matches!(mirror.fetch(2023, 8), Err(FetchError::Read(_, _))));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn quotes_config_values() {
+        assert_eq!(quoted("session=abc"), r#""session=abc""#);
+        assert_eq!(quoted("a\"\n-o /tmp/x\\"), r#""a\"\n-o /tmp/x\\""#);
+    }
+
+    #[test]
+    fn fetches_with_session() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            eprintln!("curl isn't installed, skipped");
+            return;
+        }
+
+        // a stand-in for the site, answering two requests: only with the right session
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            listener.incoming().take(2).for_each(|stream| {
+                let mut stream = stream.unwrap();
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+
+                let response = match request[0] == "GET /2023/day/1/input HTTP/1.1"
+                    && request.contains(&"Cookie: session=secret".to_string())
+                {
+                    true => "HTTP/1.1 200 OK\r\nContent-Length: 8\r\n\r\n1abc2\r\n\n",
+                    false => "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n",
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            })
+        });
+
+        assert_eq!(
+            Site::at(&url, "secret").fetch(2023, 1).unwrap(),
+            "1abc2\r\n\n"
+        );
+        assert!(matches!(
+            Site::at(&url, "wrong").fetch(2023, 1),
+            Err(FetchError::Failed(_, _))
+        ));
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+/// A small random number generator (SplitMix64) for generating puzzle inputs. The same seed always
+/// gives the same numbers, so a generated input can be reproduced from its seed.
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 up to, but not including, `limit`, which must be positive.
+    pub fn below(&mut self, limit: usize) -> usize {
+        assert!(limit > 0, "a positive limit");
+
+        // the high bits of the product are (nearly) uniform, without the bias of a modulo
+        ((self.next_u64() as u128 * limit as u128) >> 64) as usize
+    }
+
+    /// A number from `low` up to and including `high`.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    /// True with the given probability, from 0 to 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        (1..items.len()).rev().for_each(|index| {
+            let other = self.below(index + 1);
+            items.swap(index, other);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let numbers = |seed| {
+            let mut random = Random::new(seed);
+            (0..5).map(|_| random.next_u64()).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn ranges() {
+        let mut random = Random::new(1);
+
+        assert!((0..1000).all(|_| random.below(3) < 3));
+        assert!((0..1000).all(|_| (5..=7).contains(&random.between(5, 7))));
+        assert_eq!((0..1000).filter(|_| random.chance(0.0)).count(), 0);
+        assert_eq!((0..1000).filter(|_| random.chance(1.0)).count(), 1000);
+
+        let mut items = [1, 2, 3, 4, 5];
+        random.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
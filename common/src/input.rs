@@ -0,0 +1,215 @@
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::day::Day;
+use crate::fetch::{self, Fetch, FetchError, Mirror, Site, Throttled};
+
+pub const YEAR: u16 = 2023;
+
+/// Compiles the puzzle input into the day's library when its `embedded-input` feature is enabled,
+/// so it can be used as fallback when no input is given at runtime.
+#[macro_export]
+macro_rules! embedded_input {
+    ($path:literal) => {{
+        #[cfg(feature = "embedded-input")]
+        let input = Some(include_str!($path));
+        #[cfg(not(feature = "embedded-input"))]
+        let input = None;
+
+        input
+    }};
+}
+
+// Where to read the puzzle inputs from. Without any of these options the embedded input is used.
+#[derive(Args, Clone)]
+pub struct InputArguments {
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Read the puzzle inputs of this user from the inputs directory
+    #[arg(long, env = "AOC_USER")]
+    pub user: Option<String>,
+
+    /// The inputs directory, containing `<year>/<user>/dayNN.txt` [default: ~/.aoc]
+    #[arg(long, env = "AOC_INPUTS")]
+    pub inputs: Option<PathBuf>,
+
+    /// The session cookie of the user on the site, to fetch inputs missing from the inputs
+    /// directory
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Fetch missing inputs from this directory instead of the site, with `<year>/day/<day>/input`
+    /// like the site's paths
+    #[arg(long, env = "AOC_MIRROR")]
+    pub mirror: Option<PathBuf>,
+}
+
+impl InputArguments {
+    pub fn load(&self, day: &Day) -> Result<Input, InputError> {
+        match (&self.input, &self.user) {
+            (Some(path), _) if path.as_os_str() == "-" => {
+                let mut contents = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|error| InputError::Read(path.clone(), error))?;
+
+                Ok(Input {
+                    source: Source::Stdin,
+                    contents,
+                })
+            }
+            (Some(path), _) => Ok(Input {
+                source: Source::File(path.clone()),
+                contents: read(path)?,
+            }),
+            (None, Some(user)) => Ok(Input {
+                source: Source::User(user.clone()),
+                contents: Cache::new(&self.directory()).load(
+                    YEAR,
+                    day.number,
+                    user,
+                    self.fetcher().as_deref(),
+                )?,
+            }),
+            (None, None) => match day.input {
+                None => Err(InputError::Missing(day.number)),
+                Some(contents) => Ok(Input {
+                    source: Source::Embedded,
+                    contents: contents.to_string(),
+                }),
+            },
+        }
+    }
+
+    /// Where inputs missing from the inputs directory come from: the mirror, else the site when
+    /// there is a session.
+    fn fetcher(&self) -> Option<Box<dyn Fetch>> {
+        match (&self.mirror, &self.session) {
+            (Some(mirror), _) => Some(Box::new(Mirror::new(mirror))),
+            (None, Some(session)) => Some(Box::new(Throttled::new(
+                Site::new(session),
+                fetch::THROTTLE,
+                &self.directory().join("last-fetch"),
+            ))),
+            (None, None) => None,
+        }
+    }
+
+    fn directory(&self) -> PathBuf {
+        match &self.inputs {
+            Some(directory) => directory.clone(),
+            None => std::env::var_os("HOME")
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(".aoc"),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|error| InputError::Read(path.to_path_buf(), error))
+}
+
+/// The puzzle inputs of users in a directory, as `<year>/<user>/dayNN.txt`. Missing inputs are
+/// fetched and kept, so every input is fetched only once.
+pub struct Cache {
+    directory: PathBuf,
+}
+
+impl Cache {
+    pub fn new(directory: &Path) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8, user: &str) -> PathBuf {
+        self.directory
+            .join(year.to_string())
+            .join(user)
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// The input of the user for the day: from the directory, else from `fetch` when given.
+    pub fn load(
+        &self,
+        year: u16,
+        day: u8,
+        user: &str,
+        fetch: Option<&dyn Fetch>,
+    ) -> Result<String, InputError> {
+        let path = self.path(year, day, user);
+
+        match (path.exists(), fetch) {
+            (false, Some(fetch)) => {
+                let contents = fetch
+                    .fetch(year, day)
+                    .map_err(|error| InputError::Fetch(day, error))?;
+
+                path.parent()
+                    .map_or(Ok(()), std::fs::create_dir_all)
+                    .and_then(|_| std::fs::write(&path, &contents))
+                    .map_err(|error| InputError::Write(path, error))?;
+
+                Ok(contents)
+            }
+            _ => read(&path),
+        }
+    }
+}
+
+pub struct Input {
+    pub source: Source,
+    pub contents: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Embedded,
+    File(PathBuf),
+    Stdin,
+    User(String),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Embedded => f.write_str("embedded"),
+            Source::File(path) => f.write_fmt(format_args!("{}", path.display())),
+            Source::Stdin => f.write_str("stdin"),
+            Source::User(user) => f.write_str(user),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Read(PathBuf, std::io::Error),
+    Missing(u8),
+    Fetch(u8, FetchError),
+    Write(PathBuf, std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Read(path, error) => {
+                f.write_fmt(format_args!("can't read {}: {error}", path.display()))
+            }
+            InputError::Missing(day) => f.write_fmt(format_args!(
+                "no input for day {day:02}: use --input, --user or build with the embedded-input feature"
+            )),
+            InputError::Fetch(day, error) => {
+                f.write_fmt(format_args!("no input for day {day:02}: {error}"))
+            }
+            InputError::Write(path, error) => {
+                f.write_fmt(format_args!("can't write {}: {error}", path.display()))
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+mod answers;
+mod day;
+pub mod fetch;
+pub mod generate;
+mod input;
+pub mod math;
+pub mod parse;
+pub mod progress;
+mod runner;
+pub mod search;
+pub mod testing;
+mod threads;
+pub mod visual;
+
+pub use answers::{Answers, Verification};
+pub use day::{Day, Example, Generate, Model, Part, Prepare, Solution, Solve, Solver, Visualize};
+pub use input::{Cache, Input, InputArguments, InputError, Source, YEAR};
+pub use parse::ParseError;
+pub use runner::{main, Format, RunArguments, Runner};
+pub use threads::ThreadArguments;
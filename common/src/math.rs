@@ -0,0 +1,194 @@
+/// The greatest common divisor; `gcd(0, 0)` is 0.
+pub fn gcd(first: u64, second: u64) -> u64 {
+    let (mut max, mut min) = (first.max(second), first.min(second));
+
+    while min != 0 {
+        (max, min) = (min, max % min);
+    }
+
+    max
+}
+
+/// The least common multiple, or `None` when it doesn't fit a u64.
+pub fn lcm(first: u64, second: u64) -> Option<u64> {
+    match gcd(first, second) {
+        0 => Some(0),
+        divisor => (first / divisor).checked_mul(second),
+    }
+}
+
+/// The greatest common divisor `g` of `a` and `b`, with `x` and `y` such that `a * x + b * y = g`,
+/// as `(g, x, y)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a.abs(), a.signum(), 0),
+        _ => {
+            let (g, x, y) = extended_gcd(b, a % b);
+
+            (g, y, x - (a / b) * y)
+        }
+    }
+}
+
+/// The numbers `n` with `n % modulus == remainder`; the modulus must be positive.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Congruence {
+    pub remainder: u64,
+    pub modulus: u64,
+}
+
+impl Congruence {
+    /// A congruence for something that first happens at `first` and then every `period`.
+    pub fn repeating(first: u64, period: u64) -> Self {
+        Self {
+            remainder: first % period,
+            modulus: period,
+        }
+    }
+
+    /// The smallest matching number that is at least `minimum`, if it fits a u64.
+    pub fn first_from(&self, minimum: u64) -> Option<u64> {
+        match self.remainder >= minimum {
+            true => Some(self.remainder),
+            false => {
+                let periods = (minimum - self.remainder).div_ceil(self.modulus);
+
+                periods
+                    .checked_mul(self.modulus)?
+                    .checked_add(self.remainder)
+            }
+        }
+    }
+
+    /// The numbers matching both congruences, or `None` when no number does (or the combined
+    /// modulus doesn't fit a u64). The moduli don't have to be coprime.
+    pub fn combine(&self, other: &Congruence) -> Option<Congruence> {
+        let (g, x, _) = extended_gcd(self.modulus as i128, other.modulus as i128);
+        let difference = other.remainder as i128 - self.remainder as i128;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        let modulus = lcm(self.modulus, other.modulus)? as i128;
+        // the steps of self.modulus to go from self.remainder to a number matching other, in u128
+        // as both factors can be close to u64::MAX
+        let period = other.modulus as i128 / g;
+        let steps = ((difference / g).rem_euclid(period) as u128 * x.rem_euclid(period) as u128
+            % period as u128) as i128;
+        let remainder = (self.remainder as i128 + self.modulus as i128 * steps).rem_euclid(modulus);
+
+        Some(Congruence {
+            remainder: remainder as u64,
+            modulus: modulus as u64,
+        })
+    }
+}
+
+/// Solves a system of congruences with the Chinese remainder theorem: the numbers matching all of
+/// them, or `None` when there are none (or no congruences at all).
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    let mut congruences = congruences.into_iter();
+    let first = congruences.next()?;
+
+    congruences.try_fold(first, |combined, congruence| combined.combine(&congruence))
+}
+
+/// Why no number matches a system of congruences.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Unsolvable {
+    /// The congruences contradict each other, or there are none.
+    Never,
+    /// The combined modulus or the number doesn't fit a u64.
+    Overflow,
+}
+
+/// The smallest number that is at least `minimum` and matches all congruences, e.g. the first time
+/// cycles that all have started line up.
+pub fn first_common(congruences: &[Congruence], minimum: u64) -> Result<u64, Unsolvable> {
+    match crt(congruences.iter().copied()) {
+        Some(combined) => combined.first_from(minimum).ok_or(Unsolvable::Overflow),
+        None => match congruences
+            .iter()
+            .try_fold(1, |modulus, congruence| lcm(modulus, congruence.modulus))
+        {
+            None => Err(Unsolvable::Overflow),
+            Some(_) => Err(Unsolvable::Never),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn extended() {
+        let (g, x, y) = extended_gcd(240, 46);
+
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        let congruence = |remainder, modulus| Congruence { remainder, modulus };
+
+        assert_eq!(
+            crt([congruence(2, 3), congruence(3, 5), congruence(2, 7)]),
+            Some(congruence(23, 105))
+        );
+        // not coprime, but consistent
+        assert_eq!(
+            crt([congruence(3, 4), congruence(1, 6)]),
+            Some(congruence(7, 12))
+        );
+        assert_eq!(crt([congruence(0, 4), congruence(1, 6)]), None);
+
+        let large = crt([congruence(1, 4_294_967_291), congruence(2, 4_294_967_279)])
+            .expect("coprime moduli");
+        assert_eq!(large.remainder % 4_294_967_291, 1);
+        assert_eq!(large.remainder % 4_294_967_279, 2);
+        assert_eq!(crt([]), None);
+    }
+
+    #[test]
+    fn first_from() {
+        let congruence = Congruence::repeating(3, 5);
+
+        assert_eq!(congruence.first_from(0), Some(3));
+        assert_eq!(congruence.first_from(4), Some(8));
+        assert_eq!(congruence.first_from(8), Some(8));
+        assert_eq!(Congruence::repeating(0, 5).first_from(1), Some(5));
+    }
+
+    #[test]
+    fn first_common_or_why_not() {
+        let congruence = |remainder, modulus| Congruence { remainder, modulus };
+
+        assert_eq!(
+            first_common(&[congruence(1, 4), congruence(1, 6)], 2),
+            Ok(13)
+        );
+        assert_eq!(
+            first_common(&[congruence(0, 4), congruence(1, 6)], 0),
+            Err(Unsolvable::Never)
+        );
+        assert_eq!(
+            first_common(&[congruence(1, 1 << 40), congruence(2, (1 << 40) - 1)], 0),
+            Err(Unsolvable::Overflow)
+        );
+        assert_eq!(first_common(&[], 0), Err(Unsolvable::Never));
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Malformed puzzle input: what was expected, and the text found instead.
+///
+/// Parsers create it with the offending text, which should be a slice of the puzzle input. Once the
+/// error reaches the runner it is [located](ParseError::locate) in the input, adding the day, line
+/// and column to it. Input that is wrong as a whole, e.g. with an answer that doesn't fit in 64 bits,
+/// has no offending text and gives an [unlocated](ParseError::unlocated) error.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub position: Option<Position>,
+    pub text: String,
+    pub message: String,
+    address: Option<usize>,
+}
+
+/// Where the offending text starts in the input, 1-based.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the text is on, to show it in context.
+    pub line_text: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            position: None,
+            text: text.to_string(),
+            message: message.into(),
+            address: Some(text.as_ptr() as usize),
+        }
+    }
+
+    /// An error about the input as a whole, which is never given a line and column.
+    pub fn unlocated(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            position: None,
+            text: String::new(),
+            message: message.into(),
+            address: None,
+        }
+    }
+
+    /// Adds the day, and the position of the offending text in `input`. The text is found by its
+    /// address when it is a slice of `input`, or else by its first occurrence.
+    pub fn locate(mut self, day: u8, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = self.address.and_then(|address| {
+            match (start..=start + input.len()).contains(&address)
+                && input.is_char_boundary(address - start)
+            {
+                true => Some(address - start),
+                false => match self.text.is_empty() {
+                    true => None,
+                    false => input.find(&self.text),
+                },
+            }
+        });
+
+        self.day = Some(day);
+        self.position = offset.map(|offset| {
+            let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+            let line_end = input[offset..]
+                .find('\n')
+                .map_or(input.len(), |index| offset + index);
+
+            Position {
+                line: input[..offset].matches('\n').count() + 1,
+                column: input[line_start..offset].chars().count() + 1,
+                line_text: input[line_start..line_end]
+                    .trim_end_matches('\r')
+                    .to_string(),
+            }
+        });
+
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            f.write_fmt(format_args!("day {day:02}, "))?;
+        }
+        if let Some(position) = &self.position {
+            f.write_fmt(format_args!(
+                "line {}, column {}, ",
+                position.line, position.column
+            ))?;
+        }
+        match (self.address, self.text.is_empty()) {
+            (None, _) => f.write_str(&self.message)?,
+            (Some(_), true) => f.write_fmt(format_args!("{}, found nothing", self.message))?,
+            (Some(_), false) => {
+                f.write_fmt(format_args!("{}, found `{}`", self.message, self.text))?
+            }
+        }
+
+        match &self.position {
+            None => Ok(()),
+            Some(position) => f.write_fmt(format_args!(
+                "\n    {}\n    {}{}",
+                position.line_text,
+                " ".repeat(position.column - 1),
+                "^".repeat(
+                    self.text
+                        .lines()
+                        .next()
+                        .unwrap_or("")
+                        .chars()
+                        .count()
+                        .max(1)
+                )
+            )),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a number, e.g. `42` or `-7`.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, "expected a number"))
+}
+
+/// Splits `text` at the first `separator`, which is required.
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(text, format!("expected `{separator}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate() {
+        let input = "1 2\n3 x 5\n";
+        let error = number::<u32>(&input[6..7])
+            .expect_err("not a number")
+            .locate(4, input);
+
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 2,
+                column: 3,
+                line_text: "3 x 5".to_string(),
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "day 04, line 2, column 3, expected a number, found `x`\n    3 x 5\n      ^"
+        );
+    }
+
+    #[test]
+    fn locate_copied_text() {
+        let error = ParseError::new("b", "expected a").locate(1, "a\nab\n");
+
+        assert_eq!(error.position.map(|position| position.line), Some(2));
+    }
+
+    #[test]
+    fn locate_unlocated() {
+        let error = ParseError::unlocated("expected an answer").locate(1, "a\nb\n");
+
+        assert_eq!(error.position, None);
+        assert_eq!(error.to_string(), "day 01, expected an answer");
+    }
+
+    #[test]
+    fn split_once_missing() {
+        assert_eq!(
+            split_once("a b", ":").expect_err("no colon").to_string(),
+            "expected `:`, found `a b`"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use std::any::Any;
+use std::cell::OnceCell;
+use std::fmt::Arguments;
+use std::io::{IsTerminal, Write};
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Reports how far a long running part got, as a `progress: …` line on stderr, so the answers on
+/// stdout stay clean; each line is written at once, so reports from parallel work don't interleave.
+#[macro_export]
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        $crate::progress::report(format_args!($($arg)*))
+    };
+}
+
+/// How often the progress bar is redrawn.
+const TICK: Duration = Duration::from_millis(100);
+/// Parts finishing sooner than this don't get a progress bar, so fast days don't flicker.
+const QUIET: Duration = Duration::from_secs(1);
+/// How long a cancelled part gets to stop before the runner moves on without it.
+const GRACE: Duration = Duration::from_secs(1);
+/// The stack of the threads running a part, as large as a main thread's on Linux.
+const STACK: usize = 8 << 20;
+
+/// The state of a running part, shared with all threads working on it. Every part has its own, so
+/// a part that keeps running after its timeout stays cancelled, and doesn't move the progress bar
+/// of the parts after it.
+#[derive(Default)]
+struct State {
+    done: AtomicU64,
+    total: AtomicU64,
+    cancelled: AtomicBool,
+}
+
+thread_local! {
+    // The state of the part this thread works on; none outside of the runner.
+    static STATE: OnceCell<Arc<State>> = const { OnceCell::new() };
+}
+
+// Whether the progress bar is on the last line of stderr.
+static DRAWN: AtomicBool = AtomicBool::new(false);
+
+/// The payload a cancelled part unwinds with.
+struct Cancelled;
+
+/// Why a part gave no answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum Unfinished {
+    /// It was cancelled after the timeout.
+    TimedOut,
+    /// It panicked, with this message.
+    Panicked(String),
+}
+
+/// Sets how much work the running part has, in the unit it [advance]s by, for the progress bar.
+pub fn total(total: u64) {
+    STATE.with(|state| {
+        if let Some(state) = state.get() {
+            state.done.store(0, Ordering::Relaxed);
+            state.total.store(total, Ordering::Relaxed);
+        }
+    });
+    check();
+}
+
+/// Reports that `done` more work is finished, and stops the part when it is cancelled, like
+/// [check].
+pub fn advance(done: u64) {
+    STATE.with(|state| {
+        if let Some(state) = state.get() {
+            state.done.fetch_add(done, Ordering::Relaxed);
+        }
+    });
+    check();
+}
+
+/// Stops the running part when it is cancelled, e.g. because it took longer than `--timeout`.
+/// Long running parts call this regularly, or [advance]; it unwinds the part, which the runner
+/// reports as timed out.
+pub fn check() {
+    let cancelled = STATE.with(|state| {
+        state
+            .get()
+            .is_some_and(|state| state.cancelled.load(Ordering::Relaxed))
+    });
+
+    if cancelled {
+        std::panic::resume_unwind(Box::new(Cancelled))
+    }
+}
+
+#[doc(hidden)]
+pub fn report(message: Arguments) {
+    let line = format!("{}progress: {message}\n", clear());
+    let _ = std::io::stderr().lock().write_all(line.as_bytes());
+}
+
+/// Runs a part on its own threads, with a progress bar on stderr while it takes long. Fails when it
+/// is cancelled after the timeout, or when it panics, so the runner can go on with the next part:
+/// parts that don't [check] keep running in the background until the process exits.
+pub(crate) fn run<T: Send + 'static>(
+    label: &str,
+    timeout: Option<Duration>,
+    part: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Unfinished> {
+    let state = Arc::new(State::default());
+
+    // a pool of its own, as large as the configured one, so all threads of the part know its state
+    let threads = {
+        let state = Arc::clone(&state);
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(rayon::current_num_threads())
+            .stack_size(STACK)
+            .start_handler(move |_| {
+                STATE.with(|current| {
+                    let _ = current.set(Arc::clone(&state));
+                })
+            })
+            .build()
+            .expect("threads for the part")
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    threads.spawn(move || {
+        let _ = sender.send(std::panic::catch_unwind(AssertUnwindSafe(part)));
+    });
+
+    let start = Instant::now();
+    let draw = std::io::stderr().is_terminal();
+
+    let finished = loop {
+        let wait = timeout.map_or(TICK, |timeout| {
+            timeout.saturating_sub(start.elapsed()).min(TICK)
+        });
+
+        match receiver.recv_timeout(wait) {
+            Ok(finished) => break Some(finished),
+            Err(RecvTimeoutError::Disconnected) => panic!("the part sends its result"),
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+            state.cancelled.store(true, Ordering::Relaxed);
+            break receiver.recv_timeout(GRACE).ok();
+        }
+
+        if draw && start.elapsed() >= QUIET {
+            bar(label, start.elapsed(), &state);
+        }
+    };
+
+    let _ = std::io::stderr().lock().write_all(clear().as_bytes());
+
+    match finished {
+        None => Err(Unfinished::TimedOut),
+        Some(Ok(answer)) => Ok(answer),
+        Some(Err(payload)) if payload.is::<Cancelled>() => Err(Unfinished::TimedOut),
+        Some(Err(payload)) => Err(Unfinished::Panicked(panic_message(payload.as_ref()))),
+    }
+}
+
+/// The message a panic was raised with.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "a panic".to_string())
+}
+
+/// Draws the progress bar over the last line of stderr: the share done when the part told its
+/// total, else only the time it is taking.
+fn bar(label: &str, elapsed: Duration, state: &State) {
+    let (done, total) = (
+        state.done.load(Ordering::Relaxed),
+        state.total.load(Ordering::Relaxed),
+    );
+
+    let progress = match total {
+        0 => String::new(),
+        _ => {
+            let share = done.min(total) as f64 / total as f64;
+            let filled = (share * 30.0) as usize;
+            format!(
+                " [{}{}] {:>3.0}%",
+                "#".repeat(filled),
+                "-".repeat(30 - filled),
+                share * 100.0
+            )
+        }
+    };
+
+    let line = format!("{}{label}{progress} {}s", clear(), elapsed.as_secs());
+    let _ = std::io::stderr().lock().write_all(line.as_bytes());
+    DRAWN.store(true, Ordering::Relaxed);
+}
+
+/// What clears the progress bar from stderr, if it is drawn.
+fn clear() -> &'static str {
+    match DRAWN.swap(false, Ordering::Relaxed) {
+        true => "\r\x1b[2K",
+        false => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancels_after_timeout() {
+        assert_eq!(run("finishes", None, || 42), Ok(42));
+        assert_eq!(
+            run("checks", Some(Duration::from_millis(10)), || -> u32 {
+                loop {
+                    check()
+                }
+            }),
+            Err(Unfinished::TimedOut)
+        );
+    }
+
+    #[test]
+    fn reports_panics() {
+        assert_eq!(
+            run("panics", None, || -> u32 {
+                panic!("expected {}", "a number")
+            }),
+            Err(Unfinished::Panicked("expected a number".to_string()))
+        );
+        assert_eq!(run("next", None, || 42), Ok(42));
+    }
+
+    #[test]
+    fn stays_cancelled_while_the_next_part_runs() {
+        /// Tells when the part stopped, by being dropped as it unwinds.
+        struct Stopped(Arc<AtomicBool>);
+
+        impl Drop for Stopped {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Relaxed)
+            }
+        }
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let flag = Stopped(Arc::clone(&stopped));
+
+        // only checks once the runner gave up on it
+        assert_eq!(
+            run("late", Some(Duration::from_millis(10)), move || -> u32 {
+                let _flag = flag;
+                std::thread::sleep(GRACE + Duration::from_millis(300));
+                loop {
+                    check()
+                }
+            }),
+            Err(Unfinished::TimedOut)
+        );
+        assert_eq!(run("next", None, || 42), Ok(42));
+
+        std::thread::sleep(Duration::from_millis(600));
+        assert!(stopped.load(Ordering::Relaxed));
+    }
+}
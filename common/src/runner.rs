@@ -0,0 +1,389 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser, ValueEnum};
+use serde::Serialize;
+
+use crate::answers::{Answers, Verification};
+use crate::day::{Day, Model, Part};
+use crate::input::{InputArguments, Source};
+use crate::parse::ParseError;
+use crate::progress::{self, Unfinished};
+use crate::threads::ThreadArguments;
+
+/// Runs the puzzle of a single day
+#[derive(Parser)]
+struct Arguments {
+    #[command(flatten)]
+    run: RunArguments,
+}
+
+// What to run and how to verify it; shared by the binaries of the days and the aoc runner.
+#[derive(Args, Clone)]
+pub struct RunArguments {
+    /// Only run this part (1 or 2)
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    #[command(flatten)]
+    pub input: InputArguments,
+
+    #[command(flatten)]
+    pub threads: ThreadArguments,
+
+    /// Stop a part after this many seconds and report it as timed out
+    #[arg(long, env = "AOC_TIMEOUT", value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: Option<u64>,
+
+    /// Also verify against the answers in this file, in the format of answers.txt
+    #[arg(long)]
+    pub answers: Option<PathBuf>,
+
+    /// How to report the results: as text while running, or as one JSON document at the end
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Entry point for the binary of a single day.
+pub fn main(day: &Day) {
+    let mut runner = Runner::new(Arguments::parse().run);
+
+    runner.run(day);
+
+    std::process::exit(runner.finish())
+}
+
+/// Runs days, verifies their answers and keeps track of the results for the summary.
+pub struct Runner {
+    arguments: RunArguments,
+    answers: Answers,
+    results: Vec<Outcome>,
+}
+
+/// The result of solving a part for one input, as reported in JSON.
+#[derive(Serialize)]
+struct Outcome {
+    day: u8,
+    part: Part,
+    /// The name of the input, as in answers.txt.
+    input: String,
+    answer: Option<String>,
+    /// The correct answer, if known.
+    expected: Option<String>,
+    status: Status,
+    /// How the answer compares to what is known, e.g. that it is too high.
+    verification: Option<String>,
+    /// How long solving the part took; examples include parsing, as they are parsed per part.
+    nanos: Option<u64>,
+    /// How long parsing the input took, once for all parts.
+    parse_nanos: Option<u64>,
+    /// Why there is no answer: the input is missing or couldn't be parsed.
+    error: Option<String>,
+}
+
+#[derive(Serialize, Copy, Clone, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Failed,
+    Unverified,
+}
+
+/// Everything reported in JSON.
+#[derive(Serialize)]
+struct Report<'a> {
+    results: &'a [Outcome],
+    ok: usize,
+    failed: usize,
+    unverified: usize,
+}
+
+impl Runner {
+    /// Exits when the extra answers file given with `--answers` can't be used, or the threads
+    /// can't be set up.
+    pub fn new(arguments: RunArguments) -> Self {
+        if let Err(error) = arguments.threads.configure() {
+            eprintln!("{error}");
+            std::process::exit(2)
+        }
+
+        let mut answers = Answers::builtin();
+
+        if let Some(path) = &arguments.answers {
+            match std::fs::read_to_string(path)
+                .map_err(|error| error.to_string())
+                .and_then(|contents| Answers::from_str(&contents))
+            {
+                Ok(extra) => answers.extend(extra),
+                Err(error) => {
+                    eprintln!("can't use answers {}: {error}", path.display());
+                    std::process::exit(2)
+                }
+            }
+        }
+
+        Self {
+            arguments,
+            answers,
+            results: vec![],
+        }
+    }
+
+    pub fn run(&mut self, day: &Day) {
+        // the input is parsed once, into the model all parts are solved from
+        let input = self.arguments.input.load(day).map(|input| {
+            let name = input_name(&input.source);
+            let contents: Arc<str> = Arc::from(input.contents);
+            let model = self.prepare(day, &name, &contents);
+            (name, contents, model)
+        });
+
+        self.parts().into_iter().for_each(|part| {
+            day.solution(part).examples.iter().for_each(|example| {
+                let (solve, input) = (example.solve, example.input);
+                self.solve(day.number, part, example.name, input, None, move || {
+                    solve(input)
+                });
+            });
+
+            match &input {
+                Ok((name, contents, Ok((model, parse_nanos)))) => {
+                    let model = Arc::clone(model);
+                    self.solve(
+                        day.number,
+                        part,
+                        name,
+                        contents,
+                        Some(*parse_nanos),
+                        move || model.solve(part),
+                    )
+                }
+                Ok((name, _, Err(error))) => {
+                    if self.arguments.format == Format::Text {
+                        eprintln!("{part} ({name}): {error}");
+                    }
+                    self.failed(day.number, part, name, error.clone());
+                }
+                Err(error) => {
+                    if self.arguments.format == Format::Text {
+                        eprintln!("{part}: {error}");
+                    }
+                    self.failed(day.number, part, "input", error.to_string());
+                }
+            }
+        });
+    }
+
+    /// Prints the summary of all runs and returns the exit code: 1 if anything failed, else 0.
+    pub fn finish(self) -> i32 {
+        let (ok, failed, unverified) = count(self.results.iter());
+
+        match self.arguments.format {
+            Format::Text => self.summarize(),
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&Report {
+                    results: &self.results,
+                    ok,
+                    failed,
+                    unverified,
+                })
+                .expect("results as JSON")
+            ),
+        }
+
+        match failed {
+            0 => 0,
+            _ => 1,
+        }
+    }
+
+    pub fn format(&self) -> Format {
+        self.arguments.format
+    }
+
+    fn summarize(&self) {
+        let mut rows: Vec<(u8, Part)> = self
+            .results
+            .iter()
+            .map(|result| (result.day, result.part))
+            .collect();
+        rows.dedup();
+
+        println!();
+        println!("day  part  ok  failed  unverified");
+        rows.iter().for_each(|(day, part)| {
+            let (ok, failed, unverified) = count(
+                self.results
+                    .iter()
+                    .filter(|result| result.day == *day && result.part == *part),
+            );
+            println!(" {day:02}  {part:<4}  {ok:>2}  {failed:>6}  {unverified:>10}");
+        });
+
+        let (ok, failed, unverified) = count(self.results.iter());
+        println!("total     {ok:>3}  {failed:>6}  {unverified:>10}");
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.arguments.timeout.map(Duration::from_secs)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.arguments.part {
+            None => Part::all().to_vec(),
+            Some(part) => vec![part],
+        }
+    }
+
+    /// Parses an input into its model, and times it; fails with the error to report for every part.
+    fn prepare(
+        &self,
+        day: &Day,
+        name: &str,
+        input: &Arc<str>,
+    ) -> Result<(Arc<dyn Model>, u64), String> {
+        // parsed from the same text, so errors are located by the address of the offending text
+        let (prepare, shared) = (day.model, Arc::clone(input));
+        let (prepared, nanos) = progress::run(
+            &format!("day {:02} parse ({name})", day.number),
+            self.timeout(),
+            move || {
+                let start = Instant::now();
+                let prepared = prepare(&shared);
+                (prepared, start.elapsed().as_nanos() as u64)
+            },
+        )
+        .map_err(|unfinished| format!("parsing {}", self.unfinished(unfinished)))?;
+
+        prepared
+            .map(|model| (Arc::from(model), nanos))
+            .map_err(|error| error.locate(day.number, input).to_string())
+    }
+
+    /// Solves a part, and times it. The input is only used to locate errors.
+    fn solve(
+        &mut self,
+        day: u8,
+        part: Part,
+        name: &str,
+        input: &str,
+        parse_nanos: Option<u64>,
+        solve: impl FnOnce() -> Result<String, ParseError> + Send + 'static,
+    ) {
+        let (solved, nanos) = match progress::run(
+            &format!("day {day:02} {part} ({name})"),
+            self.timeout(),
+            move || {
+                let start = Instant::now();
+                let solved = solve();
+                (solved, start.elapsed().as_nanos() as u64)
+            },
+        ) {
+            Ok(solved) => solved,
+            Err(unfinished) => {
+                let error = self.unfinished(unfinished);
+
+                if self.arguments.format == Format::Text {
+                    eprintln!("{part} ({name}): {error}");
+                }
+                self.failed(day, part, name, error);
+                return;
+            }
+        };
+
+        match solved {
+            Ok(answer) => {
+                let verification = self.answers.verify(day, part, name, &answer);
+
+                if self.arguments.format == Format::Text {
+                    println!("{part} ({name}): {answer} ({verification})");
+                }
+
+                self.results.push(Outcome {
+                    day,
+                    part,
+                    input: name.to_string(),
+                    expected: self.answers.expected(day, part, name),
+                    answer: Some(answer),
+                    status: match verification {
+                        Verification::Correct => Status::Ok,
+                        _ if verification.is_failure() => Status::Failed,
+                        _ => Status::Unverified,
+                    },
+                    verification: Some(verification.to_string()),
+                    nanos: Some(nanos),
+                    parse_nanos,
+                    error: None,
+                });
+            }
+            Err(error) => {
+                let error = error.locate(day, input);
+
+                if self.arguments.format == Format::Text {
+                    eprintln!("{part} ({name}): {error}");
+                }
+                self.failed(day, part, name, error.to_string());
+            }
+        }
+    }
+
+    /// Why parsing or a part gave no answer, as reported.
+    fn unfinished(&self, unfinished: Unfinished) -> String {
+        match unfinished {
+            Unfinished::TimedOut => format!(
+                "timed out after {}s",
+                self.arguments.timeout.expect("a timeout")
+            ),
+            Unfinished::Panicked(message) => format!("panicked: {message}"),
+        }
+    }
+
+    /// Records a part that couldn't be solved at all.
+    fn failed(&mut self, day: u8, part: Part, input: &str, error: String) {
+        self.results.push(Outcome {
+            day,
+            part,
+            input: input.to_string(),
+            answer: None,
+            expected: self.answers.expected(day, part, input),
+            status: Status::Failed,
+            verification: None,
+            nanos: None,
+            parse_nanos: None,
+            error: Some(error),
+        });
+    }
+}
+
+/// The name of an input in answers.txt.
+fn input_name(source: &Source) -> String {
+    match source {
+        Source::Embedded => "input".to_string(),
+        Source::File(path) => path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        Source::Stdin => "stdin".to_string(),
+        Source::User(user) => user.clone(),
+    }
+}
+
+/// Counts the ok, failed and unverified results.
+fn count<'a>(results: impl Iterator<Item = &'a Outcome>) -> (usize, usize, usize) {
+    results.fold((0, 0, 0), |(ok, failed, unverified), result| {
+        match result.status {
+            Status::Ok => (ok + 1, failed, unverified),
+            Status::Failed => (ok, failed + 1, unverified),
+            Status::Unverified => (ok, failed, unverified + 1),
+        }
+    })
+}
@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Breadth-first search from `start`: every reachable node with its distance in steps, nearest
+/// first. Lazy, so e.g. `take_while` limits the number of steps.
+pub fn bfs<N, F, I>(start: N, neighbours: F) -> Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    Bfs {
+        queue: VecDeque::from([(start.clone(), 0)]),
+        seen: HashSet::from([start]),
+        neighbours,
+    }
+}
+
+pub struct Bfs<N, F> {
+    queue: VecDeque<(N, usize)>,
+    seen: HashSet<N>,
+    neighbours: F,
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance) = self.queue.pop_front()?;
+
+        (self.neighbours)(&node).into_iter().for_each(|neighbour| {
+            if self.seen.insert(neighbour.clone()) {
+                self.queue.push_back((neighbour, distance + 1));
+            }
+        });
+
+        Some((node, distance))
+    }
+}
+
+/// Dijkstra's shortest path: the cheapest goal node reachable from any of the starts, with its
+/// cost. `neighbours` gives the nodes reachable from a node with the cost of getting there.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(N, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    a_star(starts, neighbours, |_| C::default(), goal)
+}
+
+/// A* search: Dijkstra's shortest path, exploring the nodes that look closer to the goal first.
+/// The heuristic estimates the remaining cost, and must never overestimate it.
+pub fn a_star<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(N, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    explore(starts, neighbours, heuristic, goal).map(|(node, cost, _)| (node, cost))
+}
+
+/// Like [a_star], but with the path to the goal: all nodes from the start up to the goal.
+pub fn a_star_path<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    explore(starts, neighbours, heuristic, goal).map(|(node, cost, reached)| {
+        let mut path = vec![node];
+
+        while let Some((_, Some(previous))) = path.last().and_then(|last| reached.get(last)) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        (path, cost)
+    })
+}
+
+/// The cheapest way found to every reached node: its cost, and the node it was reached from.
+type Reached<N, C> = HashMap<N, (C, Option<N>)>;
+
+/// The A* search itself: the goal with its cost, and all reached nodes.
+fn explore<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(N, C, Reached<N, C>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached: Reached<N, C> = HashMap::new();
+    let mut queue: BinaryHeap<Candidate<N, C>> = BinaryHeap::new();
+
+    starts.into_iter().for_each(|start| {
+        reached.insert(start.clone(), (C::default(), None));
+        queue.push(Candidate {
+            estimate: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        if goal(&node) {
+            return Some((node, cost, reached));
+        }
+        if reached.get(&node).is_some_and(|(best, _)| *best < cost) {
+            continue; // already reached cheaper
+        }
+
+        neighbours(&node).into_iter().for_each(|(next, step)| {
+            let next_cost = cost + step;
+
+            if reached.get(&next).is_none_or(|(best, _)| next_cost < *best) {
+                reached.insert(next.clone(), (next_cost, Some(node.clone())));
+                queue.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        });
+    }
+
+    None
+}
+
+/// A node in the A* queue, ordered so that the lowest estimate comes out of the max-heap first.
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+/// Why [longest_path] found no path.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NoRoute {
+    /// No path leads from the start to the goal.
+    Unreachable,
+    /// A node, or one of its neighbours, is numbered 64 or higher.
+    TooManyNodes,
+}
+
+/// The longest path from `start` to `goal` that visits no node twice. Tries every path with a
+/// depth-first search, so it's only feasible for small graphs: nodes are numbered below 64, so the
+/// visited ones fit a bit mask.
+pub fn longest_path<I>(
+    start: usize,
+    goal: usize,
+    neighbours: impl Fn(usize) -> I,
+) -> Result<u64, NoRoute>
+where
+    I: IntoIterator<Item = (usize, u64)>,
+{
+    longest_route(start, goal, neighbours).map(|(length, _)| length)
+}
+
+/// Like [longest_path], but with the route: the nodes from `start` up to `goal`.
+pub fn longest_route<I>(
+    start: usize,
+    goal: usize,
+    neighbours: impl Fn(usize) -> I,
+) -> Result<(u64, Vec<usize>), NoRoute>
+where
+    I: IntoIterator<Item = (usize, u64)>,
+{
+    if start >= 64 || goal >= 64 {
+        return Err(NoRoute::TooManyNodes);
+    }
+
+    let mut longest = None;
+    let mut route = vec![start];
+
+    longest_route_from(
+        start,
+        goal,
+        1 << start,
+        0,
+        &mut route,
+        &mut longest,
+        &neighbours,
+    )?;
+
+    longest.ok_or(NoRoute::Unreachable)
+}
+
+/// Extends the route, ending at `node` after `length`, in every possible way; remembers the longest
+/// complete one. Fails on a neighbour numbered 64 or higher.
+fn longest_route_from<I>(
+    node: usize,
+    goal: usize,
+    visited: u64,
+    length: u64,
+    route: &mut Vec<usize>,
+    longest: &mut Option<(u64, Vec<usize>)>,
+    neighbours: &impl Fn(usize) -> I,
+) -> Result<(), NoRoute>
+where
+    I: IntoIterator<Item = (usize, u64)>,
+{
+    if node == goal {
+        if longest.as_ref().is_none_or(|(best, _)| length > *best) {
+            *longest = Some((length, route.clone()));
+        }
+        return Ok(());
+    }
+
+    neighbours(node)
+        .into_iter()
+        .try_for_each(|(next, step)| match next {
+            64.. => Err(NoRoute::TooManyNodes),
+            _ if visited & (1 << next) != 0 => Ok(()),
+            _ => {
+                route.push(next);
+                longest_route_from(
+                    next,
+                    goal,
+                    visited | 1 << next,
+                    length + step,
+                    route,
+                    longest,
+                    neighbours,
+                )?;
+                route.pop();
+                Ok(())
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph: 0 -1-> 1 -1-> 3, 0 -5-> 2 -1-> 3 and 1 -1-> 2.
+    fn edges(node: &usize) -> Vec<(usize, u64)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 1), (2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first() {
+        let reached: Vec<(usize, usize)> =
+            bfs(0, |node| edges(node).into_iter().map(|(next, _)| next)).collect();
+
+        assert_eq!(reached, vec![(0, 0), (1, 1), (2, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn shortest_path() {
+        assert_eq!(dijkstra([0], edges, |node| *node == 3), Some((3, 2)));
+        assert_eq!(dijkstra([0], edges, |node| *node == 2), Some((2, 2)));
+        assert_eq!(
+            dijkstra([3], edges, |node| *node == 0),
+            None::<(usize, u64)>
+        );
+        assert_eq!(
+            a_star([0], edges, |node| (*node != 3) as u64, |node| *node == 3),
+            Some((3, 2))
+        );
+        assert_eq!(
+            a_star_path([0], edges, |_| 0, |node| *node == 2),
+            Some((vec![0, 1, 2], 2))
+        );
+    }
+
+    #[test]
+    fn longest() {
+        let neighbours = |node: usize| edges(&node);
+
+        assert_eq!(longest_path(0, 3, neighbours), Ok(6));
+        assert_eq!(longest_path(1, 3, neighbours), Ok(2));
+        assert_eq!(longest_path(3, 0, neighbours), Err(NoRoute::Unreachable));
+        assert_eq!(longest_route(0, 3, neighbours), Ok((6, vec![0, 2, 3])));
+    }
+
+    #[test]
+    fn longest_with_too_many_nodes() {
+        let neighbours = |node: usize| match node {
+            0 => vec![(64, 1)],
+            _ => vec![(1, 1)],
+        };
+
+        assert_eq!(longest_path(0, 1, neighbours), Err(NoRoute::TooManyNodes));
+        assert_eq!(longest_path(64, 1, neighbours), Err(NoRoute::TooManyNodes));
+    }
+}
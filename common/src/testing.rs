@@ -0,0 +1,262 @@
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use crate::answers::{Answers, Verification};
+use crate::day::{Day, Part, Solve};
+use crate::generate::Random;
+use crate::progress::{self, Unfinished};
+
+/// The number of generated inputs per size.
+const SEEDS: u64 = 5;
+/// The number of generated cases per differential test.
+const CASES: u64 = 100;
+/// The lengths in bytes the inputs are cut to in the middle of a line, besides at every line.
+const TRUNCATED: [usize; 6] = [1, 5, 7, 33, 101, 200];
+/// How long a part may take on a truncated input before it is left alone.
+const TRUNCATED_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Asserts that every example of the part gives the answer in answers.txt.
+pub fn examples(day: &Day, part: Part) {
+    let answers = Answers::builtin();
+
+    day.solution(part).examples.iter().for_each(|example| {
+        let actual = (example.solve)(example.input)
+            .unwrap_or_else(|error| panic!("{}", error.locate(day.number, example.input)));
+
+        assert_eq!(
+            answers.verify(day.number, part, example.name, &actual),
+            Verification::Correct,
+            "day {:02} part {part} ({}): {actual}",
+            day.number,
+            example.name
+        );
+    });
+}
+
+/// Asserts that the part gives the answer in answers.txt for the embedded input. Answers that are
+/// only known to be within bounds are accepted.
+pub fn input(day: &Day, part: Part) {
+    let Some(input) = day.input else {
+        eprintln!("day {:02} has no embedded input, skipped", day.number);
+        return;
+    };
+    let actual = (day.solution(part).solve)(input)
+        .unwrap_or_else(|error| panic!("{}", error.locate(day.number, input)));
+
+    match Answers::builtin().verify(day.number, part, "input", &actual) {
+        Verification::Correct | Verification::Plausible => {}
+        verification => panic!(
+            "day {:02} part {part}: {actual} ({verification})",
+            day.number
+        ),
+    }
+}
+
+/// Asserts that the part solves inputs from the day's generator, for a few seeds per size. There are
+/// no answers to check them with, but they must parse and solve without panicking.
+pub fn generated(day: &Day, part: Part, sizes: &[usize]) {
+    let generate = day.generate.expect("a generator");
+
+    sizes.iter().for_each(|size| {
+        (0..SEEDS).for_each(|seed| {
+            let input = generate(&mut Random::new(seed), *size);
+
+            (day.solution(part).solve)(&input).unwrap_or_else(|error| {
+                panic!(
+                    "size {size}, seed {seed}: {}",
+                    error.locate(day.number, &input)
+                )
+            });
+        })
+    });
+}
+
+/// Asserts that the examples cut short, at every line and in the middle of a few, are solved or
+/// rejected with an error, but don't make the day panic. Parts taking longer than a moment are left
+/// alone.
+pub fn truncated_examples(day: &Day) {
+    Part::all().into_iter().for_each(|part| {
+        day.solution(part)
+            .examples
+            .iter()
+            .for_each(|example| truncated(day, part, example.name, example.input, example.solve))
+    });
+}
+
+/// Like [truncated_examples], for the embedded input, as by a broken download or copy.
+pub fn truncated_input(day: &Day) {
+    let Some(input) = day.input else {
+        eprintln!("day {:02} has no embedded input, skipped", day.number);
+        return;
+    };
+
+    Part::all()
+        .into_iter()
+        .for_each(|part| truncated(day, part, "input", input, day.solution(part).solve));
+}
+
+fn truncated(day: &Day, part: Part, name: &str, input: &'static str, solve: Solve) {
+    let mut lengths: Vec<usize> = input
+        .match_indices('\n')
+        .flat_map(|(index, _)| [index, index + 1])
+        .chain(TRUNCATED)
+        .filter(|length| *length < input.len() && input.is_char_boundary(*length))
+        .collect();
+    lengths.sort();
+    lengths.dedup();
+
+    lengths.into_iter().for_each(|length| {
+        let prefix = &input[..length];
+
+        // a part that doesn't check for the timeout runs on in the background until it's done
+        let solved = progress::run(name, Some(TRUNCATED_TIMEOUT), move || {
+            let _ = solve(prefix);
+        });
+
+        if let Err(Unfinished::Panicked(panic)) = solved {
+            panic!(
+                "day {:02} part {part}, first {length} bytes of the {name}: {panic}",
+                day.number
+            )
+        }
+    });
+}
+
+/// A case for a differential test that can be made smaller, to shrink a case where the solvers
+/// disagree to one that is easier to debug.
+pub trait Shrink: Sized {
+    /// Smaller variants of the value, nothing when it can't get smaller.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_numbers {
+    ($($number:ty),*) => {
+        $(impl Shrink for $number {
+            fn shrink(&self) -> Vec<Self> {
+                let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+                smaller.dedup();
+                smaller.retain(|smaller| smaller < self);
+                smaller
+            }
+        })*
+    };
+}
+
+shrink_numbers!(u8, u32, u64, usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            true => vec![false],
+            false => vec![],
+        }
+    }
+}
+
+/// Without one of the items, or with one of them smaller.
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let removed = (0..self.len()).map(|index| {
+            let mut smaller = self.clone();
+            smaller.remove(index);
+            smaller
+        });
+        let shrunk = self.iter().enumerate().flat_map(|(index, item)| {
+            item.shrink().into_iter().map(move |item| {
+                let mut smaller = self.clone();
+                smaller[index] = item;
+                smaller
+            })
+        });
+
+        removed.chain(shrunk).collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+
+        a.shrink()
+            .into_iter()
+            .map(|a| (a, b.clone()))
+            .chain(b.shrink().into_iter().map(|b| (a.clone(), b)))
+            .collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+
+        ((a.clone(), b.clone()), c.clone())
+            .shrink()
+            .into_iter()
+            .map(|((a, b), c)| (a, b, c))
+            .collect()
+    }
+}
+
+/// Asserts that an optimized solver agrees with a simple reference, e.g. a brute force one, on
+/// generated cases; a panic counts as an answer. A case where they disagree is shrunk as far as they
+/// keep disagreeing, and reported with the seed it was generated from.
+pub fn differential<C, A>(
+    generate: impl Fn(&mut Random) -> C,
+    optimized: impl Fn(&C) -> A,
+    reference: impl Fn(&C) -> A,
+) where
+    C: Shrink + Debug,
+    A: PartialEq + Debug,
+{
+    let disagree = |case: &C| answer(&optimized, case) != answer(&reference, case);
+
+    (0..CASES).for_each(|seed| {
+        let mut case = generate(&mut Random::new(seed));
+        if !disagree(&case) {
+            return;
+        }
+
+        // keep taking the first smaller case that still disagrees, until there is none
+        while let Some(smaller) = case.shrink().into_iter().find(|smaller| disagree(smaller)) {
+            case = smaller;
+        }
+
+        panic!(
+            "seed {seed}, shrunk to {case:?}: optimized {:?}, reference {:?}",
+            answer(&optimized, &case),
+            answer(&reference, &case)
+        );
+    });
+}
+
+/// The answer of a solver, or the message it panicked with.
+fn answer<C, A>(solve: impl Fn(&C) -> A, case: &C) -> Result<A, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(case)))
+        .map_err(|panic| progress::panic_message(panic.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinking() {
+        assert_eq!(10u64.shrink(), vec![0, 5, 9]);
+        assert_eq!(1u64.shrink(), vec![0]);
+        assert!(0u64.shrink().is_empty());
+        assert_eq!(vec![1u8, 0].shrink(), vec![vec![0], vec![1], vec![0, 0]]);
+        assert_eq!((1u8, true).shrink(), vec![(0, true), (1, false)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "shrunk to 10")]
+    fn shrinks_disagreements() {
+        // disagrees from 10 on, which every case does
+        differential(
+            |random| random.between(100, 1000) as u64,
+            |number| (*number).min(9),
+            |number| *number,
+        );
+    }
+}
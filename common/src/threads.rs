@@ -0,0 +1,28 @@
+use clap::Args;
+
+// How many threads the parallel days (05, 12, 13 and 20) solve with.
+#[derive(Args, Clone)]
+pub struct ThreadArguments {
+    /// Solve with this many threads [default: one per CPU]
+    #[arg(long, env = "AOC_THREADS", value_parser = clap::value_parser!(u16).range(1..))]
+    pub threads: Option<u16>,
+
+    /// Solve on a single worker thread, one item after the other, for reproducible debugging
+    #[arg(long, conflicts_with = "threads")]
+    pub sequential: bool,
+}
+
+impl ThreadArguments {
+    /// Sets up the thread pool used by all parallel work; only the first call has effect.
+    pub fn configure(&self) -> Result<(), String> {
+        let builder = match (self.sequential, self.threads) {
+            (true, _) => rayon::ThreadPoolBuilder::new().num_threads(1),
+            (false, Some(threads)) => rayon::ThreadPoolBuilder::new().num_threads(threads as usize),
+            (false, None) => return Ok(()),
+        };
+
+        builder
+            .build_global()
+            .map_err(|error| format!("can't set up the threads: {error}"))
+    }
+}
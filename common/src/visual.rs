@@ -0,0 +1,299 @@
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::time::Duration;
+
+use clap::Args;
+
+// How to draw visualizations.
+#[derive(Args, Clone)]
+pub struct VisualArguments {
+    /// Draw every frame, as an animation, instead of only the last one
+    #[arg(long)]
+    pub animate: bool,
+
+    /// Frames per second when animating
+    #[arg(long, default_value_t = 10.0, value_parser = speed)]
+    pub speed: f64,
+
+    /// Don't colour the output; neither when NO_COLOR is set to anything but an empty value
+    #[arg(long)]
+    pub no_colour: bool,
+}
+
+impl VisualArguments {
+    /// Whether to colour the output, following the NO_COLOR convention (https://no-color.org).
+    fn colours(&self) -> bool {
+        !self.no_colour && !no_color(std::env::var_os("NO_COLOR"))
+    }
+}
+
+/// Whether the value of NO_COLOR asks for no colours: set and not empty, whatever the value.
+fn no_color(value: Option<OsString>) -> bool {
+    value.is_some_and(|value| !value.is_empty())
+}
+
+fn speed(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(speed) if speed > 0.0 && speed.is_finite() => Ok(speed),
+        _ => Err(format!("unexpected speed {s}, expected a positive number")),
+    }
+}
+
+/// A colour for the terminal, drawn with an ANSI escape code.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// The colour as red, green and blue, for images.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Colour::Red => [205, 49, 49],
+            Colour::Green => [13, 188, 121],
+            Colour::Yellow => [229, 229, 16],
+            Colour::Blue => [36, 114, 200],
+            Colour::Magenta => [188, 63, 188],
+            Colour::Cyan => [17, 168, 205],
+            Colour::White => [255, 255, 255],
+            Colour::Grey => [102, 102, 102],
+        }
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 97,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// A single character of a frame, e.g. a cell of a grid, in an optional colour.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Pixel {
+    pub char: char,
+    pub colour: Option<Colour>,
+}
+
+impl Pixel {
+    pub fn new(char: char) -> Self {
+        Self { char, colour: None }
+    }
+
+    pub fn coloured(char: char, colour: Colour) -> Self {
+        Self {
+            char,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl Display for Pixel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.colour {
+            None => f.write_fmt(format_args!("{}", self.char)),
+            Some(colour) => {
+                f.write_fmt(format_args!("\x1b[{}m{}\x1b[0m", colour.code(), self.char))
+            }
+        }
+    }
+}
+
+/// A frame of a visualization: rows of pixels, with a caption below them.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Picture {
+    pub rows: Vec<Vec<Pixel>>,
+    pub caption: String,
+}
+
+impl Picture {
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl Display for Picture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.rows.iter().try_for_each(|row| {
+            row.iter()
+                .try_for_each(|pixel| f.write_fmt(format_args!("{pixel}")))?;
+            f.write_str("\n")
+        })?;
+        f.write_fmt(format_args!("{}\n", self.caption))
+    }
+}
+
+/// Where a visualization draws its frames: the terminal, or a recording for tests.
+///
+/// Unless animating, only the last frame is drawn, by [Screen::finish].
+pub struct Screen {
+    arguments: VisualArguments,
+    output: Output,
+    last: Option<Picture>,
+}
+
+enum Output {
+    Terminal,
+    Recording(Vec<String>),
+}
+
+impl Screen {
+    pub fn terminal(arguments: VisualArguments) -> Self {
+        Self {
+            arguments,
+            output: Output::Terminal,
+            last: None,
+        }
+    }
+
+    /// Keeps all frames, without colours, instead of drawing them.
+    pub fn recording() -> Self {
+        Self {
+            arguments: VisualArguments {
+                animate: true,
+                speed: 1.0,
+                no_colour: true,
+            },
+            output: Output::Recording(vec![]),
+            last: None,
+        }
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.arguments.animate
+    }
+
+    pub fn frame(&mut self, picture: Picture) {
+        match (&mut self.output, self.arguments.animate) {
+            (Output::Recording(frames), _) => frames.push(without_colours(&picture.to_string())),
+            (Output::Terminal, true) => {
+                // clear the screen and draw from the top left
+                draw(&format!("\x1b[H\x1b[2J{}", self.text(&picture)));
+                std::thread::sleep(Duration::from_secs_f64(1.0 / self.arguments.speed));
+            }
+            (Output::Terminal, false) => {}
+        }
+
+        self.last = Some(picture);
+    }
+
+    /// The last frame, e.g. to save as an image.
+    pub fn picture(&self) -> Option<&Picture> {
+        self.last.as_ref()
+    }
+
+    /// Draws the last frame, if it wasn't drawn already, and returns the recorded frames.
+    pub fn finish(self) -> Vec<String> {
+        if let (Output::Terminal, false, Some(picture)) =
+            (&self.output, self.arguments.animate, &self.last)
+        {
+            draw(&self.text(picture));
+        }
+
+        match self.output {
+            Output::Recording(frames) => frames,
+            Output::Terminal => vec![],
+        }
+    }
+
+    fn text(&self, picture: &Picture) -> String {
+        match self.arguments.colours() {
+            true => picture.to_string(),
+            false => without_colours(&picture.to_string()),
+        }
+    }
+}
+
+fn draw(frame: &str) {
+    let mut stdout = std::io::stdout().lock();
+
+    stdout
+        .write_all(frame.as_bytes())
+        .and_then(|_| stdout.flush())
+        .expect("drawing to the terminal");
+}
+
+/// Removes the ANSI escape codes that set colours.
+fn without_colours(frame: &str) -> String {
+    let mut result = String::with_capacity(frame.len());
+    let mut escaped = false;
+
+    frame.chars().for_each(|char| match (escaped, char) {
+        (false, '\x1b') => escaped = true,
+        (false, _) => result.push(char),
+        (true, 'm') => escaped = false,
+        (true, _) => {}
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixels() {
+        assert_eq!(Pixel::new('#').to_string(), "#");
+        assert_eq!(
+            Pixel::coloured('#', Colour::Red).to_string(),
+            "\x1b[31m#\x1b[0m"
+        );
+        assert_eq!(
+            without_colours(&format!(
+                "{}.{}",
+                Pixel::coloured('a', Colour::Green),
+                Pixel::coloured('b', Colour::Grey)
+            )),
+            "a.b"
+        );
+    }
+
+    #[test]
+    fn no_color_convention() {
+        assert!(!no_color(None));
+        assert!(!no_color(Some(OsString::new())));
+        assert!(no_color(Some(OsString::from("1"))));
+        assert!(no_color(Some(OsString::from("false"))));
+    }
+
+    #[test]
+    fn recording() {
+        let mut screen = Screen::recording();
+        let picture = |char, caption: &str| Picture {
+            rows: vec![vec![Pixel::coloured(char, Colour::Blue), Pixel::new('.')]],
+            caption: caption.to_string(),
+        };
+
+        screen.frame(picture('a', "first"));
+        screen.frame(picture('b', "second"));
+
+        assert_eq!(screen.picture(), Some(&picture('b', "second")));
+        assert_eq!(screen.finish(), vec!["a.\nfirst\n", "b.\nsecond\n"]);
+    }
+
+    #[test]
+    fn speeds() {
+        assert_eq!(speed("2.5"), Ok(2.5));
+        assert!(speed("0").is_err());
+        assert!(speed("fast").is_err());
+    }
+}
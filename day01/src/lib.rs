@@ -0,0 +1,638 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use std::str::FromStr;
+
+use clap::ValueEnum;
+use common::parse;
+use common::{Day, Example, ParseError, Solution, Solver};
+use serde::Serialize;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE_1: &str = include_str!("example-1.txt");
+const EXAMPLE_2: &str = include_str!("example-2.txt");
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub const DAY: Day = Day {
+    number: 1,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE_1,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE_2,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Document;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<Document, ParseError> {
+        Ok(Document {
+            text: input.to_string(),
+        })
+    }
+
+    fn part_one(document: &Document) -> Result<u32, ParseError> {
+        document.calibration(&Vocabulary::digits())
+    }
+
+    fn part_two(document: &Document) -> Result<u32, ParseError> {
+        document.calibration(&Vocabulary::english())
+    }
+}
+
+/// The tokens that spell digits in a calibration document, with the digit each one spells. Part one
+/// reads digits only, part two English words as well; others can be added, or read from a file
+/// with lines like `zwei = 2` (`#` starts a comment).
+#[derive(Clone, Debug)]
+pub struct Vocabulary {
+    /// What the tokens are, for errors: "expected a digit".
+    name: String,
+    tokens: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// The digits 0 to 9.
+    pub fn digits() -> Self {
+        Self {
+            name: "a digit".to_string(),
+            tokens: (0..10).map(|digit| (digit.to_string(), digit)).collect(),
+        }
+    }
+
+    /// The digits, and the English words for one to nine.
+    pub fn english() -> Self {
+        Self::digits()
+            .with_all(ENGLISH.iter().map(|(word, digit)| (*word, *digit)))
+            .named("a digit or a spelled out number")
+    }
+
+    /// Adds a token, e.g. `"first"` for 1. Panics when the value isn't a digit.
+    pub fn with(mut self, token: &str, digit: u32) -> Self {
+        assert!(digit < 10, "{token} spells {digit}, which isn't a digit");
+        assert!(!token.is_empty(), "tokens aren't empty");
+
+        self.tokens.retain(|(existing, _)| existing != token);
+        self.tokens.push((token.to_string(), digit));
+        self
+    }
+
+    /// Adds all tokens, e.g. those of another vocabulary; later ones replace earlier ones.
+    pub fn with_all<'a>(self, tokens: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        tokens.into_iter().fold(self, |vocabulary, (token, digit)| {
+            vocabulary.with(token, digit)
+        })
+    }
+
+    /// What the tokens are, as used in errors: "expected {name}".
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.tokens
+            .iter()
+            .map(|(token, digit)| (token.as_str(), *digit))
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    /// Reads tokens only, without the digits, from lines like `zwei = 2`; add it to another
+    /// vocabulary with [Vocabulary::with_all].
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        let empty = Self {
+            name: "a token of the vocabulary".to_string(),
+            tokens: vec![],
+        };
+
+        text.lines()
+            .map(|line| line.split('#').next().expect("a first part").trim())
+            .filter(|line| !line.is_empty())
+            .try_fold(empty, |vocabulary, line| {
+                let (token, digit) = parse::split_once(line, "=")?;
+                let (token, digit) = (token.trim(), digit.trim());
+
+                match (token.is_empty(), parse::number::<u32>(digit)?) {
+                    (true, _) => Err(ParseError::new(line, "expected a token before `=`")),
+                    (false, 10..) => Err(ParseError::new(digit, "expected a digit, 0 to 9")),
+                    (false, digit) => Ok(vocabulary.with(token, digit)),
+                }
+            })
+    }
+}
+
+/// The calibration document.
+pub struct Document {
+    text: String,
+}
+
+impl Document {
+    /// The sum of the calibration values of the lines: the first and the last digit they spell.
+    pub fn calibration(&self, vocabulary: &Vocabulary) -> Result<u32, ParseError> {
+        let scanner = Scanner::new(vocabulary);
+
+        self.text
+            .lines()
+            .map(|line| {
+                let (first, last) = scanner.first_and_last(line).ok_or_else(|| {
+                    ParseError::new(line, format!("expected {}", vocabulary.name))
+                })?;
+
+                Ok(first.value * 10 + last.value)
+            })
+            .sum()
+    }
+}
+
+/// Reads calibration values line by line from a stream, e.g. a file too large to keep in memory,
+/// with where each value came from. Lines without any token, or that aren't UTF-8, are reported as
+/// warnings.
+pub struct Calibrations<R> {
+    reader: R,
+    scanner: Scanner,
+    /// The current line, reused for every line.
+    buffer: Vec<u8>,
+    line: usize,
+    /// Where the current line starts in the stream, in bytes.
+    offset: u64,
+}
+
+impl<R: BufRead> Calibrations<R> {
+    pub fn new(reader: R, vocabulary: &Vocabulary) -> Self {
+        Self {
+            reader,
+            scanner: Scanner::new(vocabulary),
+            buffer: vec![],
+            line: 0,
+            offset: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Calibrations<R> {
+    type Item = io::Result<Result<Calibration, Warning>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.offset += self.buffer.len() as u64;
+        self.buffer.clear();
+        match self.reader.read_until(b'\n', &mut self.buffer) {
+            Err(error) => return Some(Err(error)),
+            Ok(0) => return None,
+            Ok(_) => self.line += 1,
+        }
+
+        let text = match std::str::from_utf8(&self.buffer) {
+            Ok(text) => text.trim_end_matches(['\n', '\r']),
+            Err(error) => {
+                return Some(Ok(Err(Warning::NotUtf8 {
+                    line: self.line,
+                    offset: self.offset + error.valid_up_to() as u64,
+                })))
+            }
+        };
+        let token = |found: Found| Token {
+            token: text[found.range.clone()].to_string(),
+            digit: found.value,
+            start: found.range.start,
+            end: found.range.end,
+        };
+
+        Some(Ok(match self.scanner.first_and_last(text) {
+            Some((first, last)) => Ok(Calibration {
+                line: self.line,
+                value: first.value * 10 + last.value,
+                first: token(first),
+                last: token(last),
+            }),
+            None => Err(Warning::NoDigits {
+                line: self.line,
+                text: text.to_string(),
+            }),
+        }))
+    }
+}
+
+/// The calibration value of a line, from its first and its last token.
+#[derive(Serialize, Debug, Eq, PartialEq)]
+pub struct Calibration {
+    /// The number of the line, from 1.
+    pub line: usize,
+    pub first: Token,
+    pub last: Token,
+    pub value: u32,
+}
+
+/// A token spelling a digit, at bytes `start..end` of its line.
+#[derive(Serialize, Debug, Eq, PartialEq)]
+pub struct Token {
+    pub token: String,
+    pub digit: u32,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A line that adds nothing to the sum.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// The line has no token.
+    NoDigits { line: usize, text: String },
+    /// The line isn't UTF-8, from the byte at `offset` in the stream.
+    NotUtf8 { line: usize, offset: u64 },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::NoDigits { line, text } => {
+                f.write_fmt(format_args!("line {line} has no digits: {text}"))
+            }
+            Warning::NotUtf8 { line, offset } => {
+                f.write_fmt(format_args!("line {line} isn't UTF-8 at byte {offset}"))
+            }
+        }
+    }
+}
+
+/// How [audit] reports the calibration values.
+#[derive(ValueEnum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Report {
+    /// A header, then a row per line.
+    Csv,
+    /// A JSON object per line (JSON Lines).
+    Json,
+}
+
+/// What [audit] found in total.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Audit {
+    pub lines: usize,
+    pub sum: u64,
+    pub warnings: Vec<Warning>,
+}
+
+/// Writes the calibration value of every line read to `output` as soon as it is read, and sums
+/// them up.
+pub fn audit(
+    reader: impl BufRead,
+    vocabulary: &Vocabulary,
+    report: Report,
+    mut output: impl Write,
+) -> io::Result<Audit> {
+    if report == Report::Csv {
+        writeln!(
+            output,
+            "line,first,first_digit,first_start,first_end,last,last_digit,last_start,last_end,value"
+        )?;
+    }
+
+    let mut audit = Audit {
+        lines: 0,
+        sum: 0,
+        warnings: vec![],
+    };
+
+    for calibration in Calibrations::new(reader, vocabulary) {
+        audit.lines += 1;
+
+        match calibration? {
+            Ok(calibration) => {
+                audit.sum += calibration.value as u64;
+
+                match report {
+                    Report::Csv => writeln!(
+                        output,
+                        "{},{},{},{},{},{},{},{},{},{}",
+                        calibration.line,
+                        csv_field(&calibration.first.token),
+                        calibration.first.digit,
+                        calibration.first.start,
+                        calibration.first.end,
+                        csv_field(&calibration.last.token),
+                        calibration.last.digit,
+                        calibration.last.start,
+                        calibration.last.end,
+                        calibration.value,
+                    )?,
+                    Report::Json => writeln!(
+                        output,
+                        "{}",
+                        serde_json::to_string(&calibration).expect("a calibration as JSON")
+                    )?,
+                }
+            }
+            Err(warning) => audit.warnings.push(warning),
+        }
+    }
+
+    output.flush()?;
+    Ok(audit)
+}
+
+/// Quotes a CSV field when it holds a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// A token found in a line, at `range` in bytes.
+struct Found {
+    value: u32,
+    range: Range<usize>,
+}
+
+/// Finds tokens in text in a single pass, with an Aho–Corasick automaton: it follows every byte
+/// from state to state, where a state is the longest suffix of the text so far that starts a
+/// token. Overlapping tokens, like "one" and "eight" in "oneight", are all found.
+struct Scanner {
+    /// The next state for every state and byte. A u32 holds the number of any state that fits in
+    /// memory, at a kilobyte per state.
+    next: Vec<[u32; 256]>,
+    /// The longest token ending in each state, as its length and value.
+    longest: Vec<Option<(usize, u32)>>,
+}
+
+impl Scanner {
+    fn new(vocabulary: &Vocabulary) -> Self {
+        // a trie of the tokens first, with 0 as the root and as "no transition"
+        let mut next: Vec<[u32; 256]> = vec![[0; 256]];
+        let mut longest: Vec<Option<(usize, u32)>> = vec![None];
+
+        vocabulary.tokens().for_each(|(token, value)| {
+            let state = token
+                .bytes()
+                .fold(0, |state, byte| match next[state][byte as usize] {
+                    0 => {
+                        next.push([0; 256]);
+                        longest.push(None);
+                        next[state][byte as usize] = (next.len() - 1) as u32;
+                        next.len() - 1
+                    }
+                    following => following as usize,
+                });
+            longest[state] = Some((token.len(), value));
+        });
+
+        // then the missing transitions, breadth first: those of the state for the longest proper
+        // suffix, which is nearer to the root
+        let mut queue: VecDeque<(usize, usize)> = (0..256)
+            .filter(|byte| next[0][*byte] != 0)
+            .map(|byte| (next[0][byte] as usize, 0))
+            .collect();
+
+        while let Some((state, suffix)) = queue.pop_front() {
+            if longest[state].is_none() {
+                longest[state] = longest[suffix];
+            }
+
+            (0..256).for_each(|byte| match next[state][byte] {
+                0 => next[state][byte] = next[suffix][byte],
+                following => queue.push_back((following as usize, next[suffix][byte] as usize)),
+            });
+        }
+
+        Self { next, longest }
+    }
+
+    /// The token starting first in the line and the token ending last, preferring the longest
+    /// token when several start or end at the same place.
+    fn first_and_last(&self, line: &str) -> Option<(Found, Found)> {
+        let mut state = 0;
+        let mut found: Option<(Found, Found)> = None;
+
+        line.bytes().enumerate().for_each(|(index, byte)| {
+            state = self.next[state][byte as usize] as usize;
+
+            if let Some((length, value)) = self.longest[state] {
+                let token = || Found {
+                    value,
+                    range: (index + 1 - length)..(index + 1),
+                };
+
+                found = Some(match found.take() {
+                    None => (token(), token()),
+                    Some((first, _)) if token().range.start < first.range.start => {
+                        (token(), token())
+                    }
+                    Some((first, _)) => (first, token()),
+                });
+            }
+        });
+
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let scanner = Scanner::new(&Vocabulary::english());
+        let values = |line: &str| {
+            scanner
+                .first_and_last(line)
+                .map(|(first, last)| (first.value, last.value))
+        };
+
+        assert_eq!(values("eightwo"), Some((8, 2)));
+        assert_eq!(values("xoneight"), Some((1, 8)));
+        assert_eq!(values("twone3sevenine"), Some((2, 9)));
+        assert_eq!(values("zero"), None);
+    }
+
+    #[test]
+    fn scanner_differential() {
+        // the letters of the words, so lines often spell them
+        const LETTERS: &[u8] = b"efghinorstuvwx12";
+        let vocabulary = Vocabulary::english();
+        let scanner = Scanner::new(&vocabulary);
+        let line = |letters: &Vec<u8>| -> String {
+            letters
+                .iter()
+                .map(|letter| LETTERS[*letter as usize % LETTERS.len()] as char)
+                .collect()
+        };
+
+        common::testing::differential(
+            |random| {
+                (0..random.below(30))
+                    .map(|_| random.below(LETTERS.len()) as u8)
+                    .collect::<Vec<u8>>()
+            },
+            |letters| {
+                scanner
+                    .first_and_last(&line(letters))
+                    .map(|(first, last)| (first.value, first.range, last.value, last.range))
+            },
+            |letters| {
+                let line = line(letters);
+                let found: Vec<(u32, Range<usize>)> = vocabulary
+                    .tokens()
+                    .flat_map(|(token, value)| {
+                        line.match_indices(token)
+                            .map(|(start, _)| (value, start..start + token.len()))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+
+                let first = found
+                    .iter()
+                    .min_by_key(|(_, range)| (range.start, usize::MAX - range.end))?;
+                let last = found
+                    .iter()
+                    .max_by_key(|(_, range)| (range.end, usize::MAX - range.start))?;
+
+                Some((first.0, first.1.clone(), last.0, last.1.clone()))
+            },
+        );
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let german: Vocabulary = "# German\neins = 1\nzwei = 2 # two\n\ndrei=3\n"
+            .parse()
+            .unwrap();
+        let vocabulary = Vocabulary::digits()
+            .with_all(german.tokens())
+            .with("first", 1)
+            .named("a digit or a German number");
+        let document = Puzzle::parse("xzweix\nfirst7drei\n").unwrap();
+
+        assert_eq!(document.calibration(&vocabulary), Ok(22 + 13));
+        assert_eq!(
+            document
+                .calibration(&Vocabulary::digits())
+                .map_err(|error| error.message),
+            Err("expected a digit".to_string())
+        );
+        assert!("zehn = 10".parse::<Vocabulary>().is_err());
+        assert!("zehn 10".parse::<Vocabulary>().is_err());
+    }
+
+    #[test]
+    fn audits_lines() {
+        let input = "two1nine\nnothing here\nx,one\n";
+        let mut output = vec![];
+
+        let audited = audit(
+            input.as_bytes(),
+            &Vocabulary::english().with(",one", 1),
+            Report::Csv,
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "line,first,first_digit,first_start,first_end,last,last_digit,last_start,last_end,value\n\
+             1,two,2,0,3,nine,9,4,8,29\n\
+             3,\",one\",1,1,5,\",one\",1,1,5,11\n"
+        );
+        assert_eq!(
+            audited,
+            Audit {
+                lines: 3,
+                sum: 40,
+                warnings: vec![Warning::NoDigits {
+                    line: 2,
+                    text: "nothing here".to_string()
+                }],
+            }
+        );
+
+        let mut output = vec![];
+        audit(
+            "a1b\r\n".as_bytes(),
+            &Vocabulary::digits(),
+            Report::Json,
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"{"line":1,"first":{"token":"1","digit":1,"start":1,"end":2},"last":{"token":"1","digit":1,"start":1,"end":2},"value":11}"#
+                .to_string()
+                + "\n"
+        );
+    }
+
+    #[test]
+    fn audits_lines_that_are_not_utf8() {
+        let mut output = vec![];
+        let audited = audit(
+            &b"1a\nb\xff2\n3c\n"[..],
+            &Vocabulary::digits(),
+            Report::Csv,
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(audited.sum, 11 + 33);
+        assert_eq!(
+            audited.warnings,
+            vec![Warning::NotUtf8 { line: 2, offset: 4 }]
+        );
+        assert_eq!(
+            audited.warnings[0].to_string(),
+            "line 2 isn't UTF-8 at byte 4"
+        );
+    }
+}
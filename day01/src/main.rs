@@ -1,115 +1,109 @@
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE_1: &str = include_str!("example-1.txt");
-const EXAMPLE_2: &str = include_str!("example-2.txt");
-
-const NUMBERS: [&str; 20] = [
-    "this_will_never_match",
-    "one",
-    "two",
-    "three",
-    "four",
-    "five",
-    "six",
-    "seven",
-    "eight",
-    "nine",
-    "0",
-    "1",
-    "2",
-    "3",
-    "4",
-    "5",
-    "6",
-    "7",
-    "8",
-    "9",
-];
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter};
+use std::path::PathBuf;
 
-fn main() {
-    print_answer("one (example)", &one(EXAMPLE_1), "142");
-    print_answer("one", &one(INPUT), "54450");
-    print_answer("two (example)", &two(EXAMPLE_2), "281");
-    print_answer("two", &two(INPUT), "54265");
-}
+use clap::{Args, Parser, Subcommand};
 
-fn print_answer(name: &str, actual: &str, expected: &str) {
-    match actual == expected {
-        true => println!("{name}: {actual} (OK)"),
-        false => println!("{name}: {actual} (ERROR: expected {expected})"),
-    }
-}
+use common::{Part, RunArguments, Runner};
+use day01::{Report, Vocabulary};
 
-fn one(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| -> u32 {
-            let first = number(line);
-            let second = number(&line.chars().rev().collect::<String>());
-
-            first * 10 + second
-        })
-        .sum::<u32>()
-        .to_string()
-}
+/// Runs the puzzle of day 01, or audits a calibration document
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Option<Command>,
 
-fn number(string: &str) -> u32 {
-    string
-        .chars()
-        .find(|&c| c.is_ascii_digit())
-        .expect("no digits in string")
-        .to_digit(10)
-        .expect("not an ascii digit")
+    #[command(flatten)]
+    run: RunArguments,
 }
 
-fn two(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| -> u32 {
-            let first = number_from_text(line, true);
-            let second = number_from_text(line, false);
-
-            first * 10 + second
-        })
-        .sum::<u32>()
-        .to_string()
+#[derive(Subcommand)]
+enum Command {
+    /// Report the calibration value of every line, and where it came from; lines without digits
+    /// or that aren't UTF-8 are reported as warnings on stderr
+    Audit(AuditArguments),
 }
 
-struct Answer {
-    answer: u32,
-    answer_index: usize,
+#[derive(Args)]
+struct AuditArguments {
+    /// Read the calibration document from this file instead of stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Read the digits of this part: 1 for digits only, 2 for English words too
+    #[arg(long, default_value = "2")]
+    part: Part,
+
+    /// Also read the tokens in this file, with lines like `zwei = 2`
+    #[arg(long)]
+    vocabulary: Vec<PathBuf>,
+
+    /// How to report the lines
+    #[arg(long, value_enum, default_value_t = Report::Csv)]
+    format: Report,
 }
 
-fn number_from_text(string: &str, left_to_right: bool) -> u32 {
-    let string = reverse_if_required(string, left_to_right);
-
-    NUMBERS
-        .iter()
-        .enumerate()
-        .fold(
-            Answer {
-                answer: 0,
-                answer_index: string.len(),
-            },
-            |answer, (answer_index, &candidate)| {
-                let candidate = reverse_if_required(candidate, left_to_right);
-
-                let index_of_candidate = string.find(&candidate).unwrap_or(string.len());
-
-                match index_of_candidate < answer.answer_index {
-                    true => Answer {
-                        answer: answer_index as u32 % 10,
-                        answer_index: index_of_candidate,
-                    },
-                    false => answer,
-                }
-            },
-        )
-        .answer
+fn main() {
+    let arguments = Arguments::parse();
+
+    match arguments.command {
+        None => {
+            let mut runner = Runner::new(arguments.run);
+            runner.run(&day01::DAY);
+            std::process::exit(runner.finish())
+        }
+        Some(Command::Audit(arguments)) => std::process::exit(audit(&arguments)),
+    }
 }
 
-fn reverse_if_required(string: &str, left_to_right: bool) -> String {
-    match left_to_right {
-        true => String::from(string),
-        false => string.chars().rev().collect::<String>(),
+/// Audits the document, and returns the exit code: 2 when it or a vocabulary can't be read.
+fn audit(arguments: &AuditArguments) -> i32 {
+    let vocabulary = arguments.vocabulary.iter().try_fold(
+        match arguments.part {
+            Part::One => Vocabulary::digits(),
+            Part::Two => Vocabulary::english(),
+        },
+        |vocabulary, path| {
+            let text = std::fs::read_to_string(path)
+                .map_err(|error| format!("can't read {}: {error}", path.display()))?;
+            let extra: Vocabulary = text
+                .parse()
+                .map_err(|error| format!("can't use vocabulary {}: {error}", path.display()))?;
+
+            Ok::<_, String>(vocabulary.with_all(extra.tokens()))
+        },
+    );
+
+    let reader: Result<Box<dyn BufRead>, String> = match &arguments.input {
+        None => Ok(Box::new(std::io::stdin().lock())),
+        Some(path) => File::open(path)
+            .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            .map_err(|error| format!("can't read {}: {error}", path.display())),
+    };
+
+    match vocabulary.and_then(|vocabulary| {
+        let output = BufWriter::new(std::io::stdout().lock());
+
+        day01::audit(reader?, &vocabulary, arguments.format, output)
+            .map_err(|error| error.to_string())
+    }) {
+        Ok(audit) => {
+            audit
+                .warnings
+                .iter()
+                .for_each(|warning| eprintln!("warning: {warning}"));
+            eprintln!(
+                "sum {} of {} lines, {} with warnings",
+                audit.sum,
+                audit.lines,
+                audit.warnings.len()
+            );
+            0
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            2
+        }
     }
 }
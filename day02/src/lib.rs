@@ -0,0 +1,511 @@
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use std::cmp::max;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 2,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+/// The bag of part one.
+const PUZZLE_BAG: &str = "12 red, 13 green, 14 blue";
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    draws: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Whether every draw of the game fits in the bag. Colours the bag doesn't know are an error,
+    /// rather than just not fitting.
+    fn fits_in(&self, bag: &Cubes) -> Result<bool, ParseError> {
+        self.draws
+            .iter()
+            .try_fold(true, |fits, draw| Ok(fits && draw.fits_in(bag)?))
+    }
+
+    /// The fewest cubes of every colour that make the game possible.
+    pub fn minimum_bag(&self) -> Cubes {
+        self.draws
+            .iter()
+            .fold(Cubes::default(), |bag, draw| bag.max(draw))
+    }
+
+    /// Why the game isn't possible with the bag: every colour of every draw with more cubes than
+    /// the bag holds. Empty when it is possible; colours the bag doesn't know are an error, as for
+    /// [possible].
+    pub fn violations(&self, bag: &Cubes) -> Result<Vec<Violation>, ParseError> {
+        self.draws
+            .iter()
+            .enumerate()
+            .flat_map(|(index, draw)| {
+                draw.counts.iter().map(move |(colour, drawn)| {
+                    let in_bag = bag.held(colour)?;
+
+                    Ok((*drawn > in_bag).then(|| Violation {
+                        draw: index + 1,
+                        colour: colour.clone(),
+                        drawn: *drawn,
+                        in_bag,
+                    }))
+                })
+            })
+            .filter_map(|violation| violation.transpose())
+            .collect()
+    }
+}
+
+/// A draw showing more cubes of a colour than the bag holds.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Violation {
+    /// The number of the draw in the game, from 1.
+    pub draw: usize,
+    pub colour: String,
+    pub drawn: u32,
+    pub in_bag: u32,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "draw {} shows {} {}, but the bag holds {}",
+            self.draw, self.drawn, self.colour, self.in_bag
+        ))
+    }
+}
+
+/// Cubes by colour, with any colour names: a draw from the bag, or what the bag holds.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(|colour| colour.as_str())
+    }
+
+    /// The number of cubes of all colours.
+    pub fn total(&self) -> u32 {
+        self.counts.values().sum()
+    }
+
+    /// The most cubes of every colour of both.
+    fn max(&self, other: &Self) -> Self {
+        let mut counts = self.counts.clone();
+        other.counts.iter().for_each(|(colour, count)| {
+            let most = counts.entry(colour.clone()).or_insert(0);
+            *most = max(*most, *count);
+        });
+
+        Self { counts }
+    }
+
+    /// The counts of the colours multiplied, where a missing colour counts as none.
+    fn power<'a>(&self, colours: impl Iterator<Item = &'a str>) -> u32 {
+        colours.map(|colour| self.count(colour)).product()
+    }
+
+    fn fits_in(&self, bag: &Cubes) -> Result<bool, ParseError> {
+        self.counts.iter().try_fold(true, |fits, (colour, count)| {
+            Ok(fits && *count <= bag.held(colour)?)
+        })
+    }
+
+    /// The count of a colour in the bag, which must know the colour.
+    fn held(&self, colour: &str) -> Result<u32, ParseError> {
+        self.counts.get(colour).copied().ok_or_else(|| {
+            ParseError::new(
+                colour,
+                format!(
+                    "expected a colour in the bag ({})",
+                    self.colours().collect::<Vec<_>>().join(", ")
+                ),
+            )
+        })
+    }
+}
+
+impl Display for Cubes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            &self
+                .counts
+                .iter()
+                .map(|(colour, count)| format!("{count} {colour}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+}
+
+impl FromStr for Cubes {
+    type Err = ParseError;
+
+    /// Reads cubes like `3 blue, 4 red`; lines count as commas, so a bag can be read from a file
+    /// with a colour per line.
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        text.split([',', '\n'])
+            .map(|cubes| cubes.trim())
+            .filter(|cubes| !cubes.is_empty())
+            .try_fold(Cubes::default(), |mut cubes, text| {
+                let (count, colour) = parse::split_once(text, " ")?;
+
+                match colour.is_empty() || colour.contains(char::is_whitespace) {
+                    true => Err(ParseError::new(colour, "expected a colour")),
+                    false => match cubes
+                        .counts
+                        .insert(colour.to_string(), parse::number(count)?)
+                    {
+                        Some(_) => Err(ParseError::new(colour, "expected every colour once")),
+                        None => Ok(cubes),
+                    },
+                }
+            })
+    }
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Vec<Game>;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(games: &Vec<Game>) -> Result<u32, ParseError> {
+        possible(games, &PUZZLE_BAG.parse().expect("the puzzle's bag"))
+    }
+
+    fn part_two(games: &Vec<Game>) -> Result<u32, ParseError> {
+        let colours: BTreeSet<&str> = games
+            .iter()
+            .flat_map(|g| g.draws.iter().flat_map(|d| d.colours()))
+            .collect();
+
+        Ok(games
+            .iter()
+            .map(|g| g.minimum_bag().power(colours.iter().copied()))
+            .sum())
+    }
+}
+
+/// The sum of the IDs of the games that are possible with the bag.
+pub fn possible(games: &[Game], bag: &Cubes) -> Result<u32, ParseError> {
+    games
+        .iter()
+        .map(|g| {
+            Ok(match g.fits_in(bag)? {
+                true => g.id,
+                false => 0,
+            })
+        })
+        .sum()
+}
+
+/// The smallest bag that makes all the games possible.
+pub fn minimum_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Cubes {
+    games
+        .into_iter()
+        .fold(Cubes::default(), |bag, g| bag.max(&g.minimum_bag()))
+}
+
+/// The smallest bag of at most `budget` cubes that makes the most games possible, with the IDs of
+/// those games: of the sets of games that are most possible, the one needing the fewest cubes. Only
+/// counts some game needs are worth trying for a colour, so every colour but the last gets one of
+/// those, and the last colour all the budget that is left. This takes time exponential in the
+/// number of colours, which is fine for a few.
+pub fn most_possible(games: &[Game], budget: u32) -> (Cubes, Vec<u32>) {
+    let needed: Vec<Cubes> = games.iter().map(|g| g.minimum_bag()).collect();
+    let colours: Vec<String> = minimum_bag(games)
+        .colours()
+        .map(|c| c.to_string())
+        .collect();
+
+    let mut best = (vec![], 0);
+    most_possible_from(
+        &needed,
+        &colours,
+        budget,
+        (0..games.len()).collect(),
+        &mut best,
+    );
+
+    let possible: Vec<&Game> = best.0.iter().map(|index| &games[*index]).collect();
+    (
+        minimum_bag(possible.iter().copied()),
+        possible.iter().map(|g| g.id).collect(),
+    )
+}
+
+/// Chooses counts for the remaining colours, keeping the indices of the most games possible in
+/// `best`, with the cubes they need together: `candidates` are the games still possible with the
+/// counts chosen so far.
+fn most_possible_from(
+    needed: &[Cubes],
+    colours: &[String],
+    budget: u32,
+    candidates: Vec<usize>,
+    best: &mut (Vec<usize>, u32),
+) {
+    // as many games as the best could still need fewer cubes
+    if candidates.len() < best.0.len() {
+        return;
+    }
+
+    let Some((colour, remaining)) = colours.split_first() else {
+        let total = candidates
+            .iter()
+            .fold(Cubes::default(), |bag, index| bag.max(&needed[*index]))
+            .total();
+
+        if candidates.len() > best.0.len() || total < best.1 {
+            *best = (candidates, total);
+        }
+        return;
+    };
+
+    let mut counts: Vec<u32> = match remaining.is_empty() {
+        true => vec![budget],
+        false => candidates
+            .iter()
+            .map(|index| needed[*index].count(colour))
+            .filter(|count| *count <= budget)
+            .collect(),
+    };
+    counts.sort();
+    counts.dedup();
+
+    counts.iter().rev().for_each(|count| {
+        let candidates = candidates
+            .iter()
+            .copied()
+            .filter(|index| needed[*index].count(colour) <= *count)
+            .collect();
+
+        most_possible_from(needed, remaining, budget - count, candidates, best);
+    });
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(|line| -> Result<Game, ParseError> {
+            let (game, draws) = parse::split_once(line, ": ")?;
+            let (_, id) = parse::split_once(game, " ")?;
+
+            let draws = draws
+                .split("; ")
+                .map(Cubes::from_str)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Game {
+                id: parse::number(id)?,
+                draws,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn other_colours() {
+        let games = parse("Game 1: 2 yellow, 1 red\nGame 2: 3 yellow; 1 blue\n").unwrap();
+
+        assert_eq!(
+            possible(&games, &"3 yellow, 1 red, 1 blue".parse().unwrap()),
+            Ok(3)
+        );
+        assert_eq!(
+            possible(&games, &"2 yellow\n1 red\n1 blue".parse().unwrap()),
+            Ok(1)
+        );
+        assert_eq!(
+            possible(&games, &"3 yellow, 1 red".parse().unwrap()).map_err(|e| e.message),
+            Err("expected a colour in the bag (red, yellow)".to_string())
+        );
+
+        // the first two games need no cubes of a colour another game shows, so their power is 0
+        let games =
+            parse("Game 1: 2 yellow\nGame 2: 1 red; 1 blue\nGame 3: 2 blue, 1 red, 4 yellow\n")
+                .unwrap();
+        assert_eq!(Puzzle::part_two(&games), Ok(8));
+        assert!("1 red, 2 red".parse::<Cubes>().is_err());
+        assert!("1 dark red".parse::<Cubes>().is_err());
+    }
+
+    #[test]
+    fn queries() {
+        let games = parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            minimum_bag(&games[..2]),
+            "4 red, 3 green, 6 blue".parse().unwrap()
+        );
+        assert_eq!(minimum_bag(&games).to_string(), "15 blue, 13 green, 20 red");
+
+        assert_eq!(
+            games[0].violations(&"4 red, 2 green, 6 blue".parse().unwrap()),
+            Ok(vec![])
+        );
+        assert_eq!(
+            games[2]
+                .violations(&PUZZLE_BAG.parse().unwrap())
+                .unwrap()
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>(),
+            vec!["draw 1 shows 20 red, but the bag holds 12"]
+        );
+        assert_eq!(
+            games[2]
+                .violations(&"20 red, 13 green".parse().unwrap())
+                .map_err(|e| e.message),
+            Err("expected a colour in the bag (green, red)".to_string())
+        );
+
+        // games 1, 2 and 5 need 6 blue, 3 green and 6 red together, 15 cubes
+        assert_eq!(
+            most_possible(&games, 15),
+            ("6 blue, 3 green, 6 red".parse().unwrap(), vec![1, 2, 5])
+        );
+        assert_eq!(most_possible(&games, 14).1.len(), 2);
+        assert_eq!(most_possible(&games, 48).1.len(), 5);
+        assert_eq!(most_possible(&games, 0).1, Vec::<u32>::new());
+
+        // either game alone fits the budget: the one needing fewer cubes
+        let games = parse("Game 1: 5 blue\nGame 2: 1 red\n").unwrap();
+        assert_eq!(
+            most_possible(&games, 5),
+            ("1 red".parse().unwrap(), vec![2])
+        );
+    }
+
+    #[test]
+    fn most_possible_differential() {
+        // games of up to 5 draws with up to 4 cubes of red, green and blue
+        let games = |draws: &Vec<Vec<(u8, u8, u8)>>| -> Vec<Game> {
+            draws
+                .iter()
+                .enumerate()
+                .map(|(index, draws)| Game {
+                    id: index as u32 + 1,
+                    draws: draws
+                        .iter()
+                        .map(|(red, green, blue)| {
+                            format!("{red} red, {green} green, {blue} blue")
+                                .parse()
+                                .unwrap()
+                        })
+                        .collect(),
+                })
+                .collect()
+        };
+
+        common::testing::differential(
+            |random| {
+                let games = (0..random.below(8))
+                    .map(|_| {
+                        (0..random.between(1, 5))
+                            .map(|_| {
+                                (
+                                    random.below(5) as u8,
+                                    random.below(5) as u8,
+                                    random.below(5) as u8,
+                                )
+                            })
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<(u8, u8, u8)>>>();
+
+                (games, random.below(16) as u8)
+            },
+            |(draws, budget)| {
+                let (bag, ids) = most_possible(&games(draws), *budget as u32);
+                (ids.len(), bag.total())
+            },
+            |(draws, budget)| {
+                let games = games(draws);
+
+                // every subset of the games whose minimum bag fits the budget: the most games, with
+                // the fewest cubes
+                (0..1u32 << games.len())
+                    .map(|subset| {
+                        let chosen = games
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| subset & (1 << i) != 0)
+                            .map(|(_, g)| g);
+                        (subset.count_ones() as usize, minimum_bag(chosen).total())
+                    })
+                    .filter(|(_, total)| *total <= *budget as u32)
+                    .max_by_key(|(count, total)| (*count, Reverse(*total)))
+                    .expect("the empty subset")
+            },
+        );
+    }
+}
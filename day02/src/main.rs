@@ -1,116 +1,181 @@
-use std::cmp::max;
-use std::collections::HashMap;
+use std::path::PathBuf;
 
-const INPUT: &str = include_str!("input.txt");
-const EXAMPLE: &str = include_str!("example.txt");
+use clap::{Args, Parser, Subcommand};
 
-fn main() {
-    print_answer("one (example)", &one(EXAMPLE), "8");
-    print_answer("one", &one(INPUT), "2061");
-    print_answer("two (example)", &two(EXAMPLE), "2286");
-    print_answer("two", &two(INPUT), "72596");
-}
+use common::{InputArguments, ParseError, RunArguments, Runner, Solver};
+use day02::{Cubes, Game};
 
-fn print_answer(name: &str, actual: &str, expected: &str) {
-    match actual == expected {
-        true => println!("{name}: {actual} (OK)"),
-        false => println!("{name}: {actual} (ERROR: expected {expected})"),
-    }
+/// Runs the puzzle of day 02, or asks other questions about the games
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArguments,
 }
 
-#[derive(Debug)]
-struct Game {
-    id: u32,
-    subsets: Vec<Subset>,
+#[derive(Subcommand)]
+enum Command {
+    /// Sum the IDs of the games that are possible with another bag than part one's
+    Possible {
+        #[command(flatten)]
+        bag: BagArguments,
+
+        #[command(flatten)]
+        input: InputArguments,
+    },
+    /// Explain why each impossible game isn't possible with a bag: which draws show too many cubes
+    Explain {
+        #[command(flatten)]
+        bag: BagArguments,
+
+        #[command(flatten)]
+        input: InputArguments,
+    },
+    /// Print the smallest bag that makes the games possible
+    MinimumBag {
+        /// Only make the games with these IDs possible, e.g. `1,3,7` [default: all]
+        #[arg(long, value_delimiter = ',')]
+        games: Vec<u32>,
+
+        #[command(flatten)]
+        input: InputArguments,
+    },
+    /// Print the bag of at most a number of cubes that makes the most games possible, and the IDs
+    /// of those games
+    MostPossible {
+        /// The most cubes the bag can hold, of all colours together
+        #[arg(long)]
+        budget: u32,
+
+        #[command(flatten)]
+        input: InputArguments,
+    },
 }
 
-#[derive(Debug)]
-struct Subset {
-    red: u32,
-    blue: u32,
-    green: u32,
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct BagArguments {
+    /// The cubes in the bag, like `12 red, 13 green, 14 blue`
+    #[arg(long)]
+    bag: Option<String>,
+
+    /// Read the cubes in the bag from this file, like `--bag` or with a colour per line
+    #[arg(long)]
+    bag_file: Option<PathBuf>,
 }
 
-impl Subset {
-    fn product(&self) -> u32 {
-        self.red * self.green * self.blue
+impl BagArguments {
+    fn load(&self) -> Result<Cubes, Failure> {
+        let text = match (&self.bag, &self.bag_file) {
+            (Some(bag), _) => bag.clone(),
+            (None, Some(path)) => std::fs::read_to_string(path).map_err(|error| {
+                Failure::Usage(format!("can't read {}: {error}", path.display()))
+            })?,
+            (None, None) => unreachable!("a bag is required"),
+        };
+
+        text.parse()
+            .map_err(|error: ParseError| Failure::Usage(format!("can't use the bag: {error}")))
     }
+}
+
+fn main() {
+    let arguments = Arguments::parse();
 
-    fn max(&self, other: &Self) -> Self {
-        Self {
-            red: max(self.red, other.red),
-            green: max(self.green, other.green),
-            blue: max(self.blue, other.blue),
+    std::process::exit(match arguments.command {
+        None => {
+            let mut runner = Runner::new(arguments.run);
+            runner.run(&day02::DAY);
+            runner.finish()
         }
-    }
+        Some(Command::Possible { bag, input }) => query(&input, |games| {
+            Ok(day02::possible(games, &bag.load()?)?.to_string())
+        }),
+        Some(Command::Explain { bag, input }) => query(&input, |games| {
+            let bag = bag.load()?;
 
-    fn zero() -> Self {
-        Self { red: 0, blue: 0, green: 0 }
-    }
+            Ok(games
+                .iter()
+                .map(|game| {
+                    Ok(game
+                        .violations(&bag)?
+                        .iter()
+                        .map(|violation| format!("game {}: {violation}", game.id()))
+                        .collect::<Vec<_>>())
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?
+                .concat()
+                .join("\n"))
+        }),
+        Some(Command::MinimumBag { games: ids, input }) => query(&input, |games| {
+            let unknown: Vec<String> = ids
+                .iter()
+                .filter(|id| games.iter().all(|game| game.id() != **id))
+                .map(|id| id.to_string())
+                .collect();
 
-    fn se(&self, other: &Self) -> bool {
-        self.red <= other.red && self.green <= other.green && self.blue <= other.blue
-    }
+            match unknown.is_empty() {
+                true => Ok(day02::minimum_bag(
+                    games
+                        .iter()
+                        .filter(|game| ids.is_empty() || ids.contains(&game.id())),
+                )
+                .to_string()),
+                false => Err(Failure::Usage(format!("no games {}", unknown.join(", ")))),
+            }
+        }),
+        Some(Command::MostPossible { budget, input }) => query(&input, |games| {
+            let (bag, ids) = day02::most_possible(games, budget);
+            let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+
+            Ok(format!("{bag}\n{} games: {}", ids.len(), ids.join(", ")))
+        }),
+    })
 }
 
-fn one(input: &str) -> String {
-    parse(input)
-        .iter()
-        .filter(|g| g.subsets
-            .iter()
-            .all(|s| s.se(&Subset { red: 12, green: 13, blue: 14 })))
-        .map(|g| g.id)
-        .sum::<u32>()
-        .to_string()
+/// Why a question about the games can't be answered.
+enum Failure {
+    /// The arguments or the input can't be used.
+    Usage(String),
+    Parse(ParseError),
 }
 
-fn two(input: &str) -> String {
-    parse(input)
-        .iter()
-        .map(|g| g.subsets
-            .iter()
-            .fold(Subset::zero(), |result, candidate| result.max(candidate)))
-        .map(|s| s.product())
-        .sum::<u32>()
-        .to_string()
+impl From<ParseError> for Failure {
+    fn from(error: ParseError) -> Self {
+        Failure::Parse(error)
+    }
 }
 
-fn parse(input: &str) -> Vec<Game> {
-    input
-        .lines()
-        .map(|line| -> Game {
-            line.split_once(": ").and_then(|(game, sets)| {
-                game.split_once(' ').map(|(_, id)| {
-                    let subsets = sets.split("; ").map(|s| -> HashMap<&str, u32> {
-                        s
-                            .split(", ")
-                            .map(|s| -> (&str, u32) {
-                                (
-                                    s
-                                        .split(' ')
-                                        .last()
-                                        .expect("expect a space"),
-                                    s
-                                        .split(' ')
-                                        .next()
-                                        .expect("expect a space")
-                                        .parse()
-                                        .expect("expect a number")
-                                )
-                            })
-                            .collect()
-                    }
-                    )
-                        .map(|s|
-                            Subset {
-                                red: s.get("red").copied().unwrap_or(0),
-                                blue: s.get("blue").copied().unwrap_or(0),
-                                green: s.get("green").copied().unwrap_or(0),
-                            })
-                        .collect::<Vec<_>>();
-                    Game { id: id.parse().unwrap(), subsets }
-                })
-            }).expect("it's a game")
-        })
-        .collect()
+/// Answers a question about the games of the input, and returns the exit code: 1 when the input
+/// is malformed or the games can't be checked, e.g. with colours the bag doesn't know, 2 when the
+/// arguments or the input can't be used.
+fn query(input: &InputArguments, answer: impl FnOnce(&[Game]) -> Result<String, Failure>) -> i32 {
+    let input = match input.load(&day02::DAY) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return 2;
+        }
+    };
+
+    match day02::Puzzle::parse(&input.contents)
+        .map_err(Failure::from)
+        .and_then(|games| answer(&games))
+    {
+        Ok(answer) => {
+            println!("{answer}");
+            0
+        }
+        Err(Failure::Usage(error)) => {
+            eprintln!("{error}");
+            2
+        }
+        Err(Failure::Parse(error)) => {
+            eprintln!("{}", error.locate(2, &input.contents));
+            1
+        }
+    }
 }
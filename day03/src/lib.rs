@@ -0,0 +1,176 @@
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use grid::{Grid, Position};
+use std::collections::{HashMap, HashSet};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 3,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u32, ParseError> {
+        Ok(world
+            .numbers
+            .iter()
+            .filter(|number| {
+                world
+                    .adjacent(number)
+                    .iter()
+                    .any(|position| is_symbol(world.grid[*position]))
+            })
+            .map(|number| number.value)
+            .sum())
+    }
+
+    fn part_two(world: &World) -> Result<u32, ParseError> {
+        let mut gear_to_numbers: HashMap<Position, Vec<u32>> = HashMap::new();
+
+        for number in &world.numbers {
+            world
+                .adjacent(number)
+                .into_iter()
+                .filter(|position| world.grid[*position] == GEAR)
+                .for_each(|gear| gear_to_numbers.entry(gear).or_default().push(number.value))
+        }
+
+        Ok(gear_to_numbers
+            .values()
+            .map(|numbers| {
+                if numbers.len() == 2 {
+                    numbers[0] * numbers[1]
+                } else {
+                    0
+                }
+            })
+            .sum())
+    }
+}
+
+const GEAR: char = '*';
+
+fn is_symbol(char: char) -> bool {
+    char != '.' && !char.is_ascii_digit()
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    let grid = Grid::parse(input, "expected a character", Some)?;
+
+    Ok(World {
+        numbers: input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| -> Result<Vec<Number>, ParseError> {
+                let mut numbers: Vec<Number> = Vec::new();
+                let mut start: Option<usize> = None;
+
+                for (x, char) in line.char_indices().chain([(line.len(), '.')]) {
+                    match (char.is_ascii_digit(), start) {
+                        (true, None) => start = Some(x),
+                        (false, Some(from)) => {
+                            numbers.push(Number {
+                                position: Position { x: from, y },
+                                length: x - from,
+                                value: parse::number(&line[from..x])?,
+                            });
+                            start = None
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(numbers)
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect(),
+        grid,
+    })
+}
+
+#[derive(Debug)]
+struct Number {
+    position: Position,
+    length: usize,
+    value: u32,
+}
+
+#[derive(Debug)]
+pub struct World {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+impl World {
+    /// The positions around a number, each once.
+    fn adjacent(&self, number: &Number) -> HashSet<Position> {
+        (number.position.x..number.position.x + number.length)
+            .flat_map(|x| {
+                self.grid.adjacent(Position {
+                    x,
+                    y: number.position.y,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+}
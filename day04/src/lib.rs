@@ -0,0 +1,153 @@
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 4,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Vec<Card>;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(cards: &Vec<Card>) -> Result<u32, ParseError> {
+        Ok(cards.iter().map(|card| card.points()).sum())
+    }
+
+    fn part_two(cards: &Vec<Card>) -> Result<u32, ParseError> {
+        let mut cards = cards.clone();
+
+        for index in 0..cards.len() {
+            let card = &cards[index];
+
+            let winning = card.winning() as usize;
+            let start_index = index + 1;
+            let copies = card.copies;
+
+            for copy_index in start_index..(start_index + winning) {
+                if let Some(card_to_copy) = cards.get_mut(copy_index) {
+                    card_to_copy.add_copy(copies)
+                }
+            }
+        }
+
+        Ok(cards.iter().map(|card| card.copies).sum())
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, contents) = parse::split_once(line, ":")?;
+            let (numbers, winning) = parse::split_once(contents, " | ")?;
+            let numbers: Vec<u32> = numbers
+                .trim()
+                .split(' ')
+                .filter(|number| !number.is_empty())
+                .map(parse::number)
+                .collect::<Result<_, _>>()?;
+            let winning: Vec<u32> = winning
+                .trim()
+                .split(' ')
+                .filter(|winning| !winning.is_empty())
+                .map(parse::number)
+                .collect::<Result<_, _>>()?;
+
+            Ok(Card {
+                numbers,
+                winning,
+                copies: 1,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    numbers: Vec<u32>,
+    winning: Vec<u32>,
+    copies: u32,
+}
+
+impl Card {
+    fn winning(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|&number| self.winning.contains(number))
+            .count() as u32
+    }
+
+    fn points(&self) -> u32 {
+        let count = self
+            .numbers
+            .iter()
+            .filter(|&number| self.winning.contains(number))
+            .count() as u32;
+
+        match count {
+            0 => 0,
+            n => 2u32.pow(n - 1),
+        }
+    }
+
+    fn add_copy(&mut self, count: u32) {
+        self.copies += count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+}
@@ -0,0 +1,302 @@
+use std::ops::Range;
+
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use rayon::prelude::*;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+/// The seeds of part two are searched in chunks of this many, reporting progress per chunk.
+const CHUNK: u64 = 1 << 16;
+
+pub const DAY: Day = Day {
+    number: 5,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        Ok(world
+            .seeds
+            .iter()
+            .map(|seed| world.location(*seed))
+            .min()
+            .expect("at least one"))
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        let ranges: Vec<Range<u64>> = (1..world.seeds.len())
+            .step_by(2)
+            .map(|i| (world.seeds[i - 1], world.seeds[i]))
+            .map(|(start, length)| start..(start + length))
+            .collect();
+        common::progress::total(ranges.iter().map(|range| range.end - range.start).sum());
+
+        Ok(ranges
+            .into_iter()
+            .map(|range| {
+                common::progress!("range {}-{}", range.start, range.end);
+                (0..(range.end - range.start).div_ceil(CHUNK))
+                    .into_par_iter()
+                    .map(|chunk| {
+                        // when cancelled, the chunks still queued stop without searching
+                        common::progress::check();
+                        let from = range.start + chunk * CHUNK;
+                        let seeds = from..(from + CHUNK).min(range.end);
+                        let nearest = seeds.clone().map(|seed| world.location(seed)).min();
+
+                        common::progress::advance(seeds.end - seeds.start);
+                        nearest.expect("at least one seed in a chunk")
+                    })
+                    .min()
+                    .expect("at least one for {range}")
+            })
+            .min()
+            .expect("at least one"))
+    }
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    let seeds = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(input, "expected seeds"))?;
+    let categories: Vec<Vec<&str>> = input
+        .split("\n\n")
+        .skip(1)
+        .map(|l| l.lines().collect())
+        .collect();
+
+    let seeds = parse::split_once(seeds, ": ")?
+        .1
+        .split(' ')
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+
+    let categories: Vec<Category> = categories
+        .iter()
+        .map(|c| {
+            let header = c
+                .first()
+                .ok_or_else(|| ParseError::unlocated("expected a category"))?;
+            let (from, to) = parse::split_once(parse::split_once(header, " ")?.0, "-to-")?;
+
+            let ranges: Vec<MyRange> = c
+                .iter()
+                .skip(1)
+                .map(|r| {
+                    let numbers: Vec<u64> =
+                        r.split(' ').map(parse::number).collect::<Result<_, _>>()?;
+
+                    match numbers[..] {
+                        [to, from, size] => Ok(MyRange { from, to, size }),
+                        _ => Err(ParseError::new(r, "expected 3 numbers")),
+                    }
+                })
+                .collect::<Result<_, _>>()?;
+
+            Ok(Category {
+                from: from.to_string(),
+                to: to.to_string(),
+                ranges: Ranges { ranges },
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    // the maps must lead from seeds to locations, which those of a truncated almanac don't
+    let mut from = "seed";
+    for _ in 0..=categories.len() {
+        if from == "location" {
+            return Ok(World { seeds, categories });
+        }
+        from = &categories
+            .iter()
+            .find(|category| category.from == from)
+            .ok_or_else(|| {
+                ParseError::new(&input[input.len()..], format!("expected a {from}-to- map"))
+            })?
+            .to;
+    }
+
+    Err(ParseError::new(
+        &input[input.len()..],
+        "expected the maps to lead to location",
+    ))
+}
+
+#[derive(Debug)]
+pub struct World {
+    seeds: Vec<u64>,
+    categories: Vec<Category>,
+}
+
+impl World {
+    fn location(&self, seed: u64) -> u64 {
+        let mut number = seed;
+        let mut category = self
+            .categories
+            .iter()
+            .find(|c| c.from.eq("seed"))
+            .expect("a seed category");
+
+        while category.to.ne("location") {
+            number = category.next(number);
+            category = self
+                .categories
+                .iter()
+                .find(|c| c.from == category.to)
+                .expect("category not found");
+        }
+
+        category.next(number)
+    }
+}
+
+#[derive(Debug)]
+struct Category {
+    from: String,
+    to: String,
+    ranges: Ranges,
+}
+
+impl Category {
+    fn next(&self, number: u64) -> u64 {
+        self.ranges.next(number)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Ranges {
+    ranges: Vec<MyRange>,
+}
+
+impl Ranges {
+    fn next(&self, number: u64) -> u64 {
+        match self.ranges.iter().find(|r| r.range().contains(&number)) {
+            None => number,
+            Some(range) => range.add(number),
+        }
+    }
+    //
+    // fn combine(&self, other: Self) -> Self {
+    //     let mut result = self.ranges.clone();
+    //
+    //     other.ranges.iter().for_each(|o| {
+    //         if !result.iter().any(|r| r.overlaps(o)) {
+    //             result.push(*o);
+    //         }
+    //     });
+    //
+    //     Ranges { ranges: result }
+    // }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct MyRange {
+    from: u64,
+    to: u64,
+    size: u64,
+}
+
+impl MyRange {
+    fn add(&self, number: u64) -> u64 {
+        self.to + number - self.from
+    }
+
+    fn range(&self) -> Range<u64> {
+        self.from..(self.from + self.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    #[ignore = "slow, run with cargo test --release -- --ignored"]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn range_add() {
+        let range = MyRange {
+            from: 98,
+            to: 50,
+            size: 2,
+        };
+
+        assert_eq!(range.add(98), 50);
+        assert_eq!(range.add(99), 51);
+    }
+
+    #[test]
+    fn ranges_next() {
+        let ranges = Ranges {
+            ranges: vec![
+                MyRange {
+                    from: 98,
+                    to: 50,
+                    size: 2,
+                },
+                MyRange {
+                    from: 50,
+                    to: 52,
+                    size: 48,
+                },
+            ],
+        };
+
+        assert_eq!(ranges.next(79), 81);
+        assert_eq!(ranges.next(99), 51);
+        assert_eq!(ranges.next(100), 100);
+        assert_eq!(ranges.next(13), 13);
+    }
+}
@@ -0,0 +1,224 @@
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 6,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Races;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<Races, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(races: &Races) -> Result<u64, ParseError> {
+        Ok(races.races.iter().map(|r| r.winning_count()).product())
+    }
+
+    fn part_two(races: &Races) -> Result<u64, ParseError> {
+        Ok(races
+            .joined()
+            .ok_or_else(|| ParseError::unlocated("expected the joined numbers to fit in 64 bits"))?
+            .winning_count())
+    }
+}
+
+fn parse(input: &str) -> Result<Races, ParseError> {
+    let (times_row, distances_row) = rows(input)?;
+    let times: Vec<&str> = times_row.split_whitespace().skip(1).collect();
+    let distances: Vec<&str> = distances_row.split_whitespace().skip(1).collect();
+
+    if times.is_empty() {
+        return Err(ParseError::new(times_row, "expected times of races"));
+    }
+    if times.len() != distances.len() {
+        return Err(ParseError::new(
+            distances_row,
+            format!("expected {} distances", times.len()),
+        ));
+    }
+
+    Ok(Races {
+        races: times
+            .iter()
+            .zip(distances.iter())
+            .map(|(time, distance)| {
+                Ok(Race {
+                    time: parse::number(time)?,
+                    distance: parse::number(distance)?,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// The lines with the times and the distances.
+fn rows(input: &str) -> Result<(&str, &str), ParseError> {
+    let mut lines = input.lines();
+
+    match (lines.next(), lines.next()) {
+        (Some(times), Some(distances)) => Ok((times, distances)),
+        _ => Err(ParseError::new(
+            input,
+            "expected a line with times and one with distances",
+        )),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Races {
+    races: Vec<Race>,
+}
+
+impl Races {
+    /// The races as a single race, as if the spaces between the numbers weren't there.
+    fn joined(&self) -> Option<Race> {
+        let join = |number: fn(&Race) -> u64| -> Option<u64> {
+            self.races
+                .iter()
+                .map(|race| number(race).to_string())
+                .collect::<String>()
+                .parse()
+                .ok()
+        };
+
+        Some(Race {
+            time: join(|race| race.time)?,
+            distance: join(|race| race.distance)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    /// The hold times strictly between the roots beat the record: none when the record is the best
+    /// possible distance or more.
+    fn winning_count(&self) -> u64 {
+        let (from, to) = find_roots(-1_f64, self.time as f64, -(self.distance as f64));
+
+        let to = if to == (to as u64 as f64) {
+            (to as u64).saturating_sub(1)
+        } else {
+            to as u64
+        };
+
+        to.saturating_sub(from as u64)
+    }
+}
+
+fn find_roots(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let discriminant = b * b - 4_f64 * a * c;
+
+    let sqrt_discriminant = discriminant.sqrt();
+
+    let divisor = 2_f64 * a;
+
+    let root1 = (-b + sqrt_discriminant) / divisor;
+    let root2 = (-b - sqrt_discriminant) / divisor;
+
+    (root1, root2)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn winning_count_differential() {
+        let race = |(time, distance): &(u64, u64)| Race {
+            time: *time,
+            distance: *distance,
+        };
+
+        common::testing::differential(
+            |random| {
+                let time = match random.chance(0.5) {
+                    true => random.below(10),
+                    false => random.below(100_000),
+                } as u64;
+                // the best distance is time² / 4: records at it, below it and far above it
+                let best = time * time / 4;
+                let distance = match random.below(3) {
+                    0 => (best + 1).saturating_sub(random.below(3) as u64),
+                    1 => random.next_u64() % (best + 1),
+                    _ => random.next_u64(),
+                };
+
+                (time, distance)
+            },
+            |case| race(case).winning_count(),
+            |case| {
+                let race = race(case);
+
+                (0..=race.time)
+                    .filter(|hold| hold * (race.time - hold) > race.distance)
+                    .count() as u64
+            },
+        );
+    }
+
+    #[test]
+    fn unwinnable_and_missing_races() {
+        assert_eq!(Puzzle::one("Time: 4\nDistance: 4\n"), Ok("0".to_string()));
+        assert_eq!(
+            Puzzle::one("Time:\nDistance:\n").map_err(|error| error.message),
+            Err("expected times of races".to_string())
+        );
+        assert!(Puzzle::one("Time: 4 5\nDistance: 4\n").is_err());
+    }
+}
@@ -0,0 +1,253 @@
+use common::generate::Random;
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use std::collections::{HashMap, HashSet};
+use std::ops::Shl;
+use std::panic::panic_any;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 7,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        Ok(world.winnings(false))
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        Ok(world.winnings(true))
+    }
+}
+
+const NUMBER_OF_CARD_IN_HAND: usize = 5;
+
+/// Generates distinct random hands with bids; the size is the number of hands.
+fn generate(random: &mut Random, size: usize) -> String {
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+    let size = size.min(CARDS.len().pow(NUMBER_OF_CARD_IN_HAND as u32));
+    let mut hands: HashSet<String> = HashSet::new();
+    let mut input = String::new();
+
+    while hands.len() < size {
+        let cards: String = (0..NUMBER_OF_CARD_IN_HAND)
+            .map(|_| *random.choose(&CARDS))
+            .collect();
+
+        if hands.insert(cards.clone()) {
+            input.push_str(&format!("{cards} {}\n", random.between(1, 1000)));
+        }
+    }
+
+    input
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    Ok(World {
+        hands: input
+            .lines()
+            .map(|l| parse::split_once(l, " "))
+            .map(|split| {
+                let (cards, bid) = split?;
+
+                if cards.chars().count() != NUMBER_OF_CARD_IN_HAND {
+                    return Err(ParseError::new(
+                        cards,
+                        format!("expected {NUMBER_OF_CARD_IN_HAND} cards"),
+                    ));
+                }
+
+                if let Some((offset, char)) = cards
+                    .char_indices()
+                    .find(|(_, char)| card_value(*char, false).is_none())
+                {
+                    return Err(ParseError::new(
+                        &cards[offset..offset + char.len_utf8()],
+                        "expected a card",
+                    ));
+                }
+
+                Ok(Hand {
+                    cards: cards.to_string(),
+                    bid: parse::number(bid)?,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// The value of a card, where a jack is the weakest card when it's a joker.
+fn card_value(card: char, joker: bool) -> Option<u32> {
+    match card {
+        'A' => Some(14),
+        'K' => Some(13),
+        'Q' => Some(12),
+        'J' => match joker {
+            false => Some(11),
+            true => Some(1),
+        },
+        'T' => Some(10),
+        '2'..='9' => card.to_digit(10),
+        _ => None,
+    }
+}
+
+fn type_value(cards: &str, joker: bool) -> u32 {
+    match joker {
+        true => permutations(cards.to_string())
+            .iter()
+            .map(|c| type_value(c, false))
+            .max()
+            .expect("at least one"),
+        false => {
+            let map = cards.chars().fold(HashMap::new(), |mut acc, c| {
+                *acc.entry(c).or_insert(0) += 1;
+                acc
+            });
+
+            let max_same_kind_count = map.iter().max_by_key(|(_, v)| **v).expect("at least one").1;
+
+            let card_kind_count = map.len();
+
+            match card_kind_count {
+                1 => 7,
+                2 => match max_same_kind_count {
+                    4 => 6,
+                    3 => 5,
+                    _ => panic_any("expected 4 or 3"),
+                },
+                3 => match max_same_kind_count {
+                    3 => 4,
+                    2 => 3,
+                    _ => panic_any("expected 3 or 2"),
+                },
+                4 => 2,
+                5 => 1,
+                _ => panic_any("expect max 5"),
+            }
+        }
+    }
+}
+
+fn permutations(cards: String) -> Vec<String> {
+    let characters: HashSet<char> = HashSet::from_iter(cards.chars());
+
+    match (characters.len(), characters.contains(&'J')) {
+        (0..=1, _) => vec![cards],
+        (_, false) => vec![cards],
+        (_, true) => characters
+            .iter()
+            .filter(|c| *c != &'J')
+            .flat_map(|c| permutations(cards.replacen('J', &c.to_string(), 1)))
+            .collect(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    hands: Vec<Hand>,
+}
+
+impl World {
+    fn winnings(&self, joker: bool) -> u64 {
+        let mut hands = self.hands.clone();
+
+        hands.sort_by_cached_key(|h| h.strength(joker));
+
+        hands
+            .iter()
+            .enumerate()
+            .map(|(index, hand)| hand.bid * (index as u64 + 1))
+            .sum::<u64>()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Hand {
+    cards: String,
+    bid: u64,
+}
+
+impl Hand {
+    /// The type of the hand, and then the values of its cards, in the bits of a single number to
+    /// compare hands by.
+    fn strength(&self, joker: bool) -> u32 {
+        self.cards
+            .chars()
+            .enumerate()
+            .map(|(index, card)| {
+                card_value(card, joker)
+                    .expect("a card")
+                    .shl((4 - index) * NUMBER_OF_CARD_IN_HAND)
+            })
+            .fold(0, |acc, value| acc | value)
+            | type_value(&self.cards, joker).shl(5 * NUMBER_OF_CARD_IN_HAND)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 10, 1000]);
+        common::testing::generated(&DAY, Two, &[1, 10, 1000]);
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use common::math::{self, Congruence, Unsolvable};
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+use crate::Move::{Left, Right};
+use crate::Part::{One, Two};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE_1: &str = include_str!("example-1.txt");
+const EXAMPLE_2: &str = include_str!("example-2.txt");
+const EXAMPLE_3: &str = include_str!("example-3.txt");
+
+pub const DAY: Day = Day {
+    number: 8,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[
+            Example {
+                name: "example-1",
+                input: EXAMPLE_1,
+                solve: Puzzle::one,
+            },
+            Example {
+                name: "example-2",
+                input: EXAMPLE_2,
+                solve: Puzzle::one,
+            },
+        ],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE_3,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        if !["AAA", "ZZZ"]
+            .iter()
+            .all(|name| world.nodes.contains_key(*name))
+        {
+            return Err(ParseError::unlocated("expected nodes AAA and ZZZ"));
+        }
+
+        Ok(world
+            .find(
+                &Position {
+                    name: "AAA".to_string(),
+                    steps: 0,
+                },
+                &One,
+            )?
+            .steps)
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        let ghosts: Vec<(u64, Congruence)> = world
+            .nodes
+            .values()
+            .filter(|n| n.name.ends_with('A'))
+            .map(|n| world.cycle(&n.name))
+            .collect::<Result<_, _>>()?;
+
+        if ghosts.is_empty() {
+            return Err(ParseError::unlocated("expected a node ending with A"));
+        }
+
+        let all_started = ghosts.iter().map(|(first, _)| *first).max().unwrap_or(0);
+        let cycles: Vec<Congruence> = ghosts.iter().map(|(_, cycle)| *cycle).collect();
+
+        math::first_common(&cycles, all_started).map_err(|unsolvable| match unsolvable {
+            Unsolvable::Never => ParseError::unlocated("expected the ghosts to end up together"),
+            Unsolvable::Overflow => ParseError::unlocated(
+                "expected the steps until the ghosts end up together to fit in 64 bits",
+            ),
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    let (moves, nodes) = parse::split_once(input, "\n\n")?;
+    if moves.is_empty() {
+        return Err(ParseError::new(moves, "expected L or R moves"));
+    }
+
+    let nodes: Vec<(&str, &str, &str)> = nodes
+        .lines()
+        .map(|l| {
+            let (name, targets) = parse::split_once(l, " = ")?;
+            let targets = targets
+                .strip_prefix('(')
+                .and_then(|targets| targets.strip_suffix(')'))
+                .ok_or_else(|| ParseError::new(targets, "expected (left, right)"))?;
+            let (left, right) = parse::split_once(targets, ", ")?;
+
+            Ok((name, left, right))
+        })
+        .collect::<Result<_, _>>()?;
+
+    if let Some(unknown) = nodes
+        .iter()
+        .flat_map(|(_, left, right)| [left, right])
+        .find(|target| !nodes.iter().any(|(name, _, _)| name == *target))
+    {
+        return Err(ParseError::new(unknown, "expected a known node"));
+    }
+
+    Ok(World {
+        moves: moves
+            .char_indices()
+            .map(|(index, c)| match c {
+                'L' => Ok(Left),
+                'R' => Ok(Right),
+                _ => Err(ParseError::new(
+                    &moves[index..index + c.len_utf8()],
+                    "expected L or R",
+                )),
+            })
+            .collect::<Result<_, _>>()?,
+        nodes: nodes
+            .iter()
+            .map(|(name, left, right)| {
+                let node = Node {
+                    name: name.to_string(),
+                    left: left.to_string(),
+                    right: right.to_string(),
+                };
+
+                (node.name.clone(), node)
+            })
+            .collect(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    moves: Vec<Move>,
+    nodes: HashMap<String, Node>,
+}
+
+impl World {
+    fn go(&self, from: &String, to: &Move) -> Result<String, ParseError> {
+        let destination = self
+            .nodes
+            .get(from)
+            .ok_or_else(|| ParseError::unlocated(format!("expected a node {from}")))?;
+
+        Ok(match to {
+            Left => destination.left.clone(),
+            Right => destination.right.clone(),
+        })
+    }
+
+    /// The next end node for the part after `from`.
+    fn find(&self, from: &Position, part: &Part) -> Result<Position, ParseError> {
+        let mut steps = from.steps;
+        let mut name = from.name.clone();
+        let number_of_moves = self.moves.len() as u64;
+        // after a step for every node and next move, every step repeats one taken before
+        let limit = from.steps + number_of_moves * self.nodes.len() as u64;
+
+        while steps == from.steps || !Self::is_end(&name, part) {
+            if steps > limit {
+                return Err(ParseError::unlocated(format!(
+                    "expected {} to reach {}",
+                    from.name,
+                    match part {
+                        One => "ZZZ",
+                        Two => "a node ending with Z",
+                    }
+                )));
+            }
+
+            let next_move = self.moves[(steps % number_of_moves) as usize];
+            name = self.go(&name, &next_move)?;
+            steps += 1;
+        }
+
+        Ok(Position { name, steps })
+    }
+
+    /// When a ghost starting at `from` first reaches an end node, and at which steps it's at an
+    /// end node from then on. Fails when the ghost doesn't reach end nodes at a regular interval.
+    fn cycle(&self, from: &str) -> Result<(u64, Congruence), ParseError> {
+        let moves = self.moves.len() as u64;
+        let mut ends = vec![self.find(
+            &Position {
+                name: from.to_string(),
+                steps: 0,
+            },
+            &Two,
+        )?];
+
+        // at the same node with the same next move, the ghost repeats what it did before
+        let start = loop {
+            let next = self.find(ends.last().expect("an end node"), &Two)?;
+            let repeated = ends
+                .iter()
+                .position(|end| end.name == next.name && end.steps % moves == next.steps % moves);
+            ends.push(next);
+
+            if let Some(start) = repeated {
+                break start;
+            }
+        };
+
+        let steps: Vec<u64> = ends[start..].iter().map(|end| end.steps).collect();
+        let period = steps[1] - steps[0];
+
+        match steps.windows(2).all(|pair| pair[1] - pair[0] == period) {
+            true => Ok((steps[0], Congruence::repeating(steps[0], period))),
+            false => Err(ParseError::unlocated(format!(
+                "expected {from} to reach end nodes at a regular interval"
+            ))),
+        }
+    }
+
+    fn is_end(name: &String, part: &Part) -> bool {
+        match part {
+            One => name == "ZZZ",
+            Two => name.ends_with('Z'),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Move {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Position {
+    name: String,
+    steps: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn two_offset_cycles() {
+        // ghost 1 is at 1Z after 1, 3, 5, ... steps, ghost 2 at 2Z after 4, 7, 10, ... steps
+        let input = "L\n\n1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1Z, 1Z)\n2A = (2B, 2B)\n\
+            2B = (2C, 2C)\n2C = (2D, 2D)\n2D = (2Z, 2Z)\n2Z = (2E, 2E)\n2E = (2F, 2F)\n\
+            2F = (2Z, 2Z)\n";
+
+        assert_eq!(Puzzle::two(input).expect("valid input"), "7");
+    }
+
+    #[test]
+    fn unreachable_ends() {
+        let error = |input: &str, solve: fn(&str) -> Result<String, ParseError>| {
+            solve(input).map_err(|error| error.message)
+        };
+
+        assert_eq!(
+            error("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n", Puzzle::one),
+            Err("expected L or R moves".to_string())
+        );
+        assert_eq!(
+            error("L\n\nAAA = (AAA, AAA)\n", Puzzle::one),
+            Err("expected nodes AAA and ZZZ".to_string())
+        );
+        assert_eq!(
+            error(
+                "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n",
+                Puzzle::one
+            ),
+            Err("expected AAA to reach ZZZ".to_string())
+        );
+        // the ghost is at an end node after 1, 2, 4, 5, 7, 8, ... steps
+        assert_eq!(
+            error(
+                "L\n\n1A = (1Z, 1Z)\n1Z = (2Z, 2Z)\n2Z = (1B, 1B)\n1B = (1Z, 1Z)\n",
+                Puzzle::two
+            ),
+            Err("expected 1A to reach end nodes at a regular interval".to_string())
+        );
+    }
+}
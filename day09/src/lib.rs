@@ -0,0 +1,164 @@
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 9,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = i64;
+    type Two = i64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<i64, ParseError> {
+        Ok(world.values.iter().map(|v| v.next()).sum())
+    }
+
+    fn part_two(world: &World) -> Result<i64, ParseError> {
+        Ok(world.values.iter().map(|v| v.prev()).sum())
+    }
+}
+
+fn parse(input: &str) -> Result<World, ParseError> {
+    Ok(World {
+        values: input
+            .lines()
+            .map(|l| {
+                let history: Vec<i64> = l
+                    .split_whitespace()
+                    .map(parse::number)
+                    .collect::<Result<_, _>>()?;
+
+                if history.is_empty() {
+                    return Err(ParseError::new(l, "expected numbers"));
+                }
+
+                // a history that isn't extrapolated by the last difference is cut short
+                let value = Value { history };
+                match value.diffs().last().is_some_and(|zeros| !zeros.is_empty()) {
+                    true => Ok(value),
+                    false => Err(ParseError::new(
+                        l,
+                        "expected differences that end up all zeros",
+                    )),
+                }
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    values: Vec<Value>,
+}
+
+#[derive(Debug, Clone)]
+struct Value {
+    history: Vec<i64>,
+}
+
+impl Value {
+    fn diffs(&self) -> Vec<Vec<i64>> {
+        let mut diffs: Vec<Vec<i64>> = Vec::new();
+        diffs.push(self.history.clone());
+
+        while !diffs.last().expect("not empty").iter().all(|v| *v == 0) {
+            let last = diffs.last().expect("not empty");
+            let next: Vec<i64> = (1..last.len()).map(|i| last[i] - last[i - 1]).collect();
+
+            diffs.push(next)
+        }
+
+        diffs
+    }
+
+    fn next(&self) -> i64 {
+        self.diffs()
+            .iter()
+            .map(|d| d.last().expect("at least one"))
+            .sum()
+    }
+
+    fn prev(&self) -> i64 {
+        self.diffs()
+            .iter()
+            .rev()
+            .map(|d| *d.first().expect("at least one"))
+            .reduce(|acc, i| i - acc)
+            .expect("at least one")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn value_next() {
+        assert_eq!(value(&[0, 3, 6, 9, 12, 15]).next(), 18);
+        assert_eq!(value(&[10, 13, 16, 21, 30, 45]).next(), 68);
+    }
+
+    #[test]
+    fn value_prev() {
+        assert_eq!(value(&[0, 3, 6, 9, 12, 15]).prev(), -3);
+        assert_eq!(value(&[10, 13, 16, 21, 30, 45]).prev(), 5);
+    }
+
+    fn value(history: &[i64]) -> Value {
+        Value {
+            history: history.to_vec(),
+        }
+    }
+}
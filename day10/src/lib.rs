@@ -0,0 +1,600 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Write};
+
+use common::generate::Random;
+use common::visual::{Colour, Pixel, Screen};
+use common::{search, Day, Example, ParseError, Part, Solution, Solver};
+use grid::Direction::{self, Down, Left, Right, Up};
+use grid::{Grid, Position};
+
+use crate::Connection::*;
+use crate::Square::{Ground, Pipe, Start};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE_1: &str = include_str!("example_1.txt");
+const EXAMPLE_2: &str = include_str!("example_2.txt");
+const EXAMPLE_3A: &str = include_str!("example_3a.txt");
+const EXAMPLE_3B: &str = include_str!("example_3b.txt");
+const EXAMPLE_4: &str = include_str!("example_4.txt");
+const EXAMPLE_5: &str = include_str!("example_5.txt");
+
+/// The most frames to trace the loop in.
+const FRAMES: usize = 50;
+
+pub const DAY: Day = Day {
+    number: 10,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[
+            Example {
+                name: "example-1",
+                input: EXAMPLE_1,
+                solve: Puzzle::one,
+            },
+            Example {
+                name: "example-2",
+                input: EXAMPLE_2,
+                solve: Puzzle::one,
+            },
+        ],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[
+            Example {
+                name: "example-3a",
+                input: EXAMPLE_3A,
+                solve: Puzzle::two,
+            },
+            Example {
+                name: "example-3b",
+                input: EXAMPLE_3B,
+                solve: Puzzle::two,
+            },
+            Example {
+                name: "example-4",
+                input: EXAMPLE_4,
+                solve: Puzzle::two,
+            },
+            Example {
+                name: "example-5",
+                input: EXAMPLE_5,
+                solve: Puzzle::two,
+            },
+        ],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<usize, ParseError> {
+        Ok(world.farthest())
+    }
+
+    fn part_two(world: &World) -> Result<usize, ParseError> {
+        Ok(enclosed(&world.path()?).len())
+    }
+}
+
+/// Draws the loop: traced from the start for part one, with the tiles it encloses for part two.
+fn visualize(input: &str, part: Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let world = World::parse(input)?;
+    let (start, _) = world.start();
+    let reached: Vec<(Position, usize)> =
+        search::bfs(start, |position| world.connected(*position)).collect();
+    let farthest = reached.last().map(|(_, steps)| *steps).unwrap_or(0);
+
+    match part {
+        Part::One => {
+            let every = (farthest / FRAMES).max(1);
+            let frames: Vec<usize> = match screen.is_animated() {
+                true => (0..farthest).step_by(every).chain([farthest]).collect(),
+                false => vec![farthest],
+            };
+
+            frames.into_iter().for_each(|steps| {
+                let traced: HashSet<Position> = reached
+                    .iter()
+                    .take_while(|(_, distance)| *distance <= steps)
+                    .map(|(position, _)| *position)
+                    .collect();
+
+                screen.frame(
+                    world
+                        .draw(&traced, &HashSet::new())
+                        .picture(format!("steps: {steps}")),
+                );
+            });
+        }
+        Part::Two => {
+            let path = world.path()?;
+            let inside: HashSet<Position> = enclosed(&path).into_iter().collect();
+
+            screen.frame(
+                world
+                    .draw(&path.into_iter().collect(), &inside)
+                    .picture(format!("enclosed: {}", inside.len())),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The tiles enclosed by the loop. The loop is drawn at double size, so the outside can be filled
+/// in between pipes that touch; what isn't filled is inside.
+fn enclosed(path: &[Position]) -> Vec<Position> {
+    let mut map = map(path);
+
+    fill(&mut map, Position { x: 0, y: 0 }, 'O');
+
+    let space = map.find(|c| *c == ' ').expect("a space");
+    fill(&mut map, space, '#');
+
+    map.iter()
+        .filter(|(position, c)| position.x % 2 == 1 && position.y % 2 == 1 && **c == '#')
+        .map(|(position, _)| Position {
+            x: position.x / 2,
+            y: position.y / 2,
+        })
+        .collect()
+}
+
+/// Generates a maze of pipes with one loop, and junk pipes around it; the size is the number of
+/// blocks the loop encloses across. The loop is the outline of a random blob of blocks, without
+/// holes and without blocks touching only at a corner, so the outline never touches itself.
+fn generate(random: &mut Random, size: usize) -> String {
+    let blocks = size.max(1);
+    // with a border of empty blocks, so the outside is connected
+    let mut blob = Grid::filled(blocks + 2, blocks + 2, false);
+    let first = Position {
+        x: random.between(1, blocks),
+        y: random.between(1, blocks),
+    };
+    blob[first] = true;
+    let mut grown = vec![first];
+
+    let mut attempts = blocks * blocks * 4;
+    while grown.len() < (blocks * blocks).div_ceil(2) && attempts > 0 {
+        let from = *random.choose(&grown);
+        let next = blob.step(from, *random.choose(&Direction::ALL));
+
+        if let Some(next) = next.filter(|next| {
+            (1..=blocks).contains(&next.x)
+                && (1..=blocks).contains(&next.y)
+                && !blob[*next]
+                && !touches_at_corner(&blob, *next)
+        }) {
+            blob[next] = true;
+            grown.push(next);
+        }
+        attempts -= 1;
+    }
+
+    // fill the holes: what can't be reached from the outside
+    let outside: HashSet<Position> = search::bfs(Position { x: 0, y: 0 }, |position| {
+        blob.neighbours(*position)
+            .filter(|next| !blob[*next])
+            .collect::<Vec<Position>>()
+    })
+    .map(|(position, _)| position)
+    .collect();
+    let blob = blob.map_with_position(|position, block| *block || !outside.contains(&position));
+
+    // the outline, on tiles with two per block plus one: block (x, y) has its corners on tiles
+    // (2x, 2y) to (2x + 2, 2y + 2)
+    let mut connections: Grid<Vec<Direction>> =
+        Grid::filled(blob.width() * 2 + 1, blob.height() * 2 + 1, vec![]);
+    blob.iter()
+        .filter(|(_, block)| **block)
+        .for_each(|(position, _)| {
+            Direction::ALL
+                .into_iter()
+                .filter(|direction| {
+                    !blob
+                        .step(position, *direction)
+                        .is_some_and(|next| blob[next])
+                })
+                .for_each(|direction| {
+                    let (x, y) = (position.x * 2, position.y * 2);
+                    let (from, along) = match direction {
+                        Up => (Position { x, y }, Right),
+                        Down => (Position { x, y: y + 2 }, Right),
+                        Left => (Position { x, y }, Down),
+                        Right => (Position { x: x + 2, y }, Down),
+                    };
+                    let middle = from.step(along).expect("a tile");
+                    let to = middle.step(along).expect("a tile");
+
+                    connections[from].push(along);
+                    connections[middle].extend([along.opposite(), along]);
+                    connections[to].push(along.opposite());
+                })
+        });
+
+    let on_loop: Vec<Position> = connections
+        .iter()
+        .filter(|(_, directions)| !directions.is_empty())
+        .map(|(position, _)| position)
+        .collect();
+    let start = *random.choose(&on_loop);
+
+    let tiles: Vec<char> = connections
+        .iter()
+        .map(|(position, directions)| {
+            let mut directions = directions.clone();
+            directions.sort();
+
+            match directions[..] {
+                _ if position == start => 'S',
+                [Up, Down] => '|',
+                [Right, Left] => '-',
+                [Up, Right] => 'L',
+                [Up, Left] => 'J',
+                [Down, Left] => '7',
+                [Right, Down] => 'F',
+                [] if connections.neighbours(start).any(|next| next == position) => '.',
+                [] => *random.choose(&['.', '.', '.', '|', '-', 'L', 'J', '7', 'F']),
+                _ => panic!("expected an outline touching every tile at most once"),
+            }
+        })
+        .collect();
+
+    Grid::new(connections.width(), connections.height(), tiles).to_string()
+}
+
+/// Whether adding the block would make two blocks touch only at a corner, in one of the four
+/// squares of two by two blocks around it.
+fn touches_at_corner(blob: &Grid<bool>, block: Position) -> bool {
+    [(-1, -1), (0, -1), (-1, 0), (0, 0)].iter().any(|(dx, dy)| {
+        let filled = |x: isize, y: isize| {
+            blob.offset(block, dx + x, dy + y)
+                .is_some_and(|position| position == block || blob[position])
+        };
+
+        filled(0, 0) == filled(1, 1) && filled(1, 0) == filled(0, 1) && filled(0, 0) != filled(1, 0)
+    })
+}
+
+fn fill(map: &mut Grid<char>, start: Position, with: char) {
+    let replace = map[start];
+    let area: Vec<Position> = search::bfs(start, |p| {
+        map.neighbours(*p)
+            .filter(|next| map[*next] == replace)
+            .collect::<Vec<Position>>()
+    })
+    .map(|(p, _)| p)
+    .collect();
+
+    area.into_iter().for_each(|p| map[p] = with);
+}
+
+fn map(path: &[Position]) -> Grid<char> {
+    let width = path.iter().max_by_key(|c| c.x).expect("y").x;
+    let heigth = path.iter().max_by_key(|c| c.y).expect("x").y;
+
+    let multiplier = 2usize;
+    let border = 1usize;
+
+    let map_width = width * multiplier + 2 * border * multiplier;
+    let map_height = heigth * multiplier + 2 * border * multiplier;
+
+    let mut result = Grid::filled(map_width, map_height, ' ');
+
+    let mut circular_path = Vec::from_iter(path);
+    circular_path.push(&path[0]);
+
+    circular_path.windows(2).for_each(|c| {
+        let from = &c[0];
+        let to = &c[1];
+
+        let from_x = from.x.min(to.x) * multiplier + border;
+        let from_y = from.y.min(to.y) * multiplier + border;
+
+        let to_x = from.x.max(to.x) * multiplier + border;
+        let to_y = from.y.max(to.y) * multiplier + border;
+
+        (from_x..=to_x).for_each(|x| {
+            (from_y..=to_y).for_each(|y| {
+                result[Position { x, y }] = '*';
+            })
+        });
+    });
+
+    result
+}
+
+#[derive(Clone)]
+pub struct World {
+    squares: Grid<Square>,
+}
+
+impl World {
+    fn start_position(&self) -> Position {
+        self.squares
+            .find(|square| *square == Start)
+            .expect("a starting point")
+    }
+
+    fn start(&self) -> (Position, Direction) {
+        let position = self.start_position();
+
+        let direction = *self
+            .start_directions(position)
+            .first()
+            .expect("at least one");
+
+        (position, direction)
+    }
+
+    /// The directions from the start with a pipe connecting back to it.
+    fn start_directions(&self, start: Position) -> Vec<Direction> {
+        [Up, Down, Right, Left]
+            .into_iter()
+            .filter(|d| {
+                let square = self.next(start, d).map(|next| &self.squares[next]);
+                match square {
+                    Some(Pipe(p)) => p.directions().contains(&d.opposite()),
+                    _ => false,
+                }
+            })
+            .collect()
+    }
+
+    /// The positions a square's pipe connects to.
+    fn connected(&self, position: Position) -> Vec<Position> {
+        let directions = match &self.squares[position] {
+            Pipe(p) => p.directions().to_vec(),
+            Ground => vec![],
+            Start => self.start_directions(position),
+        };
+
+        directions
+            .iter()
+            .filter_map(|d| self.next(position, d))
+            .collect()
+    }
+
+    /// The number of steps along the loop to the point farthest from the start.
+    fn farthest(&self) -> usize {
+        let (start, _) = self.start();
+
+        search::bfs(start, |position| self.connected(*position))
+            .map(|(_, steps)| steps)
+            .max()
+            .expect("the start")
+    }
+
+    fn next(&self, from: Position, direction: &Direction) -> Option<Position> {
+        self.squares.step(from, *direction)
+    }
+
+    /// The squares, with the loop (or the part traced of it) and the tiles inside it coloured.
+    fn draw(&self, on_loop: &HashSet<Position>, inside: &HashSet<Position>) -> Grid<Pixel> {
+        self.squares
+            .map_with_position(|position, square| match square {
+                Start => Pixel::coloured('S', Colour::Yellow),
+                _ if on_loop.contains(&position) => Pixel::coloured(square.char(), Colour::Green),
+                _ if inside.contains(&position) => Pixel::coloured('•', Colour::Red),
+                _ => Pixel::coloured(square.char(), Colour::Grey),
+            })
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let start = input
+            .find('S')
+            .map(|index| &input[index..index + 1])
+            .ok_or_else(|| ParseError::new(input, "expected a start S"))?;
+
+        let world = Self {
+            squares: Grid::parse(input, "expected a square", Square::parse)?,
+        };
+
+        match world.start_directions(world.start_position()).len() {
+            2 => Ok(world),
+            _ => Err(ParseError::new(
+                start,
+                "expected the start to connect to two pipes",
+            )),
+        }
+    }
+
+    /// The positions on the loop, starting at the start.
+    fn path(&self) -> Result<Vec<Position>, ParseError> {
+        let (start, direction) = self.start();
+
+        let mut current_position: Position = start;
+        let mut current_direction: Direction = direction;
+        let mut result: Vec<Position> = Vec::new();
+
+        loop {
+            result.push(current_position);
+            current_position = self
+                .next(current_position, &current_direction)
+                .ok_or_else(|| ParseError::unlocated("expected the loop to stay on the map"))?;
+            let next_square = &self.squares[current_position];
+
+            current_direction = match next_square {
+                Pipe(c) if c.directions().contains(&current_direction.opposite()) => {
+                    c.next_direction(&current_direction)
+                }
+                Pipe(_) | Ground => {
+                    return Err(ParseError::unlocated(
+                        "expected the pipes from the start to loop back to it",
+                    ))
+                }
+                Start => break,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Display for World {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.squares.fmt(f)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum Square {
+    Pipe(Connection),
+    Ground,
+    Start,
+}
+
+impl Square {
+    fn parse(input: char) -> Option<Self> {
+        match input {
+            '|' => Some(Pipe(NS)),
+            '-' => Some(Pipe(EW)),
+            'L' => Some(Pipe(NE)),
+            'J' => Some(Pipe(NW)),
+            '7' => Some(Pipe(SW)),
+            'F' => Some(Pipe(SE)),
+            '.' => Some(Ground),
+            'S' => Some(Start),
+            _ => None,
+        }
+    }
+
+    fn char(&self) -> char {
+        match self {
+            Pipe(p) => p.char(),
+            Ground => ' ',
+            Start => 'S',
+        }
+    }
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.char())
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum Connection {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+}
+
+impl Connection {
+    fn directions(&self) -> [Direction; 2] {
+        match self {
+            NS => [Up, Down],
+            EW => [Right, Left],
+            NE => [Up, Right],
+            NW => [Up, Left],
+            SW => [Down, Left],
+            SE => [Down, Right],
+        }
+    }
+
+    fn char(&self) -> char {
+        match self {
+            NS => '│',
+            EW => '─',
+            NE => '└',
+            NW => '┘',
+            SW => '┐',
+            SE => '┌',
+        }
+    }
+}
+
+impl Connection {
+    fn next_direction(&self, from: &Direction) -> Direction {
+        let directions = self.directions();
+
+        let one = directions[0];
+        let two = directions[1];
+
+        match one == from.opposite() {
+            true => two,
+            false => one,
+        }
+    }
+}
+
+impl Display for Connection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn broken_loops() {
+        assert_eq!(
+            World::parse(".S-\n")
+                .map(drop)
+                .map_err(|error| error.message),
+            Err("expected the start to connect to two pipes".to_string())
+        );
+        assert!(Puzzle::two("-S-\n").is_err());
+        assert!(Puzzle::two("F-7\nS.|\nL-7\n").is_err());
+    }
+
+    #[test]
+    fn visualize_examples() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE_2, One, &mut screen).expect("example");
+        visualize(EXAMPLE_3A, Two, &mut screen).expect("example");
+        let frames = screen.finish();
+
+        assert_eq!(frames.len(), 9 + 1);
+        assert!(frames[8].ends_with("steps: 8\n"));
+        assert!(frames[9].ends_with("enclosed: 4\n"));
+        assert_eq!(frames[9].matches('•').count(), 4);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 2, 10, 30]);
+        common::testing::generated(&DAY, Two, &[1, 2, 10, 30]);
+    }
+}
@@ -0,0 +1,155 @@
+use common::{Day, Example, ParseError, Solution, Solver};
+use grid::{Grid, Point};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 11,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[
+            Example {
+                name: "example-expansion-10",
+                input: EXAMPLE,
+                solve: |input| Ok(World::parse(input)?.total_distance(10).to_string()),
+            },
+            Example {
+                name: "example-expansion-100",
+                input: EXAMPLE,
+                solve: |input| Ok(World::parse(input)?.total_distance(100).to_string()),
+            },
+        ],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        Ok(world.total_distance(2))
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        Ok(world.total_distance(1_000_000))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    image: Grid<bool>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let image = Grid::parse(input, "expected # or .", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        match image.find(|planet| *planet) {
+            Some(_) => Ok(Self { image }),
+            None => Err(ParseError::new(
+                &input[input.len()..],
+                "expected a galaxy #",
+            )),
+        }
+    }
+
+    /// The sum of the distances between all pairs of planets, after expanding the universe.
+    fn total_distance(&self, expansion: u64) -> u64 {
+        connections(&self.expanded_planets(expansion))
+            .iter()
+            .map(|(a, b)| a.manhattan_distance(**b))
+            .sum()
+    }
+
+    /// The planets, after growing every empty row and column to `expansion` rows or columns.
+    fn expanded_planets(&self, expansion: u64) -> Vec<Point<u64>> {
+        let empty_rows: Vec<usize> = self
+            .image
+            .rows()
+            .map(|row| !row.contains(&true))
+            .enumerate()
+            .filter_map(|(y, empty)| empty.then_some(y))
+            .collect();
+        let empty_columns: Vec<usize> = self
+            .image
+            .columns()
+            .map(|mut column| !column.any(|planet| *planet))
+            .enumerate()
+            .filter_map(|(x, empty)| empty.then_some(x))
+            .collect();
+
+        let expand = |empty: &[usize], at: usize| {
+            at as u64 + (expansion - 1) * empty.iter().filter(|empty| **empty < at).count() as u64
+        };
+
+        self.image
+            .iter()
+            .filter(|(_, planet)| **planet)
+            .map(|(position, _)| Point {
+                x: expand(&empty_columns, position.x),
+                y: expand(&empty_rows, position.y),
+            })
+            .collect()
+    }
+}
+
+fn connections(planets: &[Point<u64>]) -> Vec<(&Point<u64>, &Point<u64>)> {
+    (0..planets.len())
+        .flat_map(|i| {
+            ((i + 1)..planets.len())
+                .map(|j| (&planets[i], &planets[j]))
+                .collect::<Vec<(&Point<u64>, &Point<u64>)>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+}
@@ -0,0 +1,356 @@
+use common::generate::Random;
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 12,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        world.candidates(1)
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        world.candidates(5)
+    }
+}
+
+/// Generates random records, with damaged springs where some springs are unknown; the size is the
+/// number of records. Every record has at least one arrangement: the springs it was made from.
+fn generate(random: &mut Random, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = random.between(1, 20);
+            let mut springs: Vec<char> = (0..length)
+                .map(|_| match random.chance(0.5) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect();
+            if !springs.contains(&'#') {
+                springs[random.below(length)] = '#';
+            }
+
+            let groups: Vec<String> = springs
+                .split(|spring| *spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+            let record: String = springs
+                .iter()
+                .map(|spring| match random.chance(0.4) {
+                    true => '?',
+                    false => *spring,
+                })
+                .collect();
+
+            format!("{record} {}\n", groups.join(","))
+        })
+        .collect()
+}
+
+pub struct World {
+    records: Vec<Record>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            records: input.lines().map(Record::parse).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// The number of arrangements of all records, unfolded to `copies` copies.
+    fn candidates(&self, copies: u32) -> Result<u64, ParseError> {
+        let unfolded: Vec<Springs> = self
+            .records
+            .iter()
+            .map(|record| record.unfolded(copies))
+            .collect::<Result<_, _>>()?;
+
+        Ok(unfolded
+            .par_iter()
+            .map(|springs| {
+                let mut cache: HashMap<CacheKey, u64> = HashMap::new();
+                springs.candidates(0, 0, &mut cache)
+            })
+            .sum::<u64>())
+    }
+}
+
+struct Record {
+    springs: String,
+    groups: Vec<u32>,
+}
+
+impl Record {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (springs, groups) = parse::split_once(input, " ")?;
+
+        if let Some((index, c)) = springs
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '#' | '.' | '?'))
+        {
+            return Err(ParseError::new(
+                &springs[index..index + c.len_utf8()],
+                "expected #, . or ?",
+            ));
+        }
+
+        Ok(Self {
+            springs: springs.to_string(),
+            groups: groups
+                .split(',')
+                .map(parse::number)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// The record unfolded to `copies` copies, as bitmasks; fails when it's too long for them.
+    fn unfolded(&self, copies: u32) -> Result<Springs, ParseError> {
+        if (self.springs.len() as u32 + 1) * copies - 1 > u128::BITS {
+            return Err(ParseError::new(&self.springs, "expected fewer springs"));
+        }
+
+        let springs = (0..copies)
+            .map(|_| self.springs.as_str())
+            .collect::<Vec<&str>>()
+            .join("?");
+
+        let length = springs.len() as u32;
+        let mut has_spring = 0u128;
+        let mut has_no_spring = 0u128;
+        springs.chars().enumerate().for_each(|(i, c)| {
+            let bit = 2u128.pow(length - i as u32 - 1);
+            match c {
+                '#' => has_spring |= bit,
+                '.' => has_no_spring |= bit,
+                _ => {}
+            };
+        });
+
+        Ok(Springs {
+            length,
+            has_spring,
+            has_no_spring,
+            groups: self.groups.repeat(copies as usize),
+        })
+    }
+}
+
+struct Springs {
+    length: u32,
+    has_spring: u128,
+    has_no_spring: u128,
+    groups: Vec<u32>,
+}
+
+impl Springs {
+    fn candidates(&self, from: u32, group_index: usize, cache: &mut HashMap<CacheKey, u64>) -> u64 {
+        let group_size = self.groups[group_index];
+        let remaining_groups: u32 = self.groups[group_index + 1..].iter().sum::<u32>();
+
+        let mut result = 0;
+
+        let mut passed_spring = false;
+        let mut from = from;
+
+        while !passed_spring
+            && from + remaining_groups <= self.length
+            && from + group_size <= self.length
+        {
+            let mask = (2u128.pow(group_size) - 1) << (self.length - group_size - from);
+
+            let final_group = group_index == self.groups.len() - 1;
+            let has_remaining_positions = self.length - from - group_size > 0;
+            let no_negative_springs = self.has_no_spring & mask == 0;
+            let possible_no_spring_after = !has_remaining_positions
+                || 1u128 << (self.length - group_size - from - 1) & self.has_spring == 0;
+            let possible_no_tail = !final_group
+                || !has_remaining_positions
+                || (2u128.pow(self.length - from - group_size) - 1) & self.has_spring == 0;
+
+            if no_negative_springs && possible_no_spring_after && possible_no_tail {
+                result += match final_group {
+                    true => 1u64,
+                    false => {
+                        let new_from = from + group_size + 1;
+                        let new_group_index = group_index + 1;
+
+                        let cache_key = CacheKey {
+                            from: new_from,
+                            group_index: new_group_index,
+                        };
+
+                        let result = cache.get(&cache_key);
+
+                        match result {
+                            None => {
+                                let value = self.candidates(new_from, new_group_index, cache);
+
+                                cache.insert(cache_key, value);
+
+                                value
+                            }
+                            Some(value) => *value,
+                        }
+                    }
+                }
+            }
+
+            from += 1;
+
+            passed_spring = 2u128.pow(self.length - from) & self.has_spring != 0
+        }
+
+        result
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct CacheKey {
+    from: u32,
+    group_index: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 10, 100]);
+        common::testing::generated(&DAY, Two, &[1, 10, 100]);
+    }
+
+    #[test]
+    fn candidates_differential() {
+        // springs as 0 for ., 1 for # and 2 for ?, groups of one more than their number, and one
+        // or two copies: small enough to try all arrangements
+        let record = |(springs, groups, copies): &(Vec<u8>, Vec<u8>, bool)| {
+            let springs: String = match springs.is_empty() {
+                true => ".".to_string(),
+                false => springs
+                    .iter()
+                    .map(|spring| ['.', '#', '?'][*spring as usize % 3])
+                    .collect(),
+            };
+            let groups: Vec<u32> = match groups.is_empty() {
+                true => vec![1],
+                false => groups.iter().map(|group| *group as u32 % 4 + 1).collect(),
+            };
+            let copies = match copies {
+                true => 2,
+                false => 1,
+            };
+
+            (springs, groups, copies)
+        };
+
+        common::testing::differential(
+            |random| {
+                let springs: Vec<u8> = (0..random.between(1, 10))
+                    .map(|_| random.below(3) as u8)
+                    .collect();
+                let groups: Vec<u8> = (0..random.between(1, 4))
+                    .map(|_| random.below(4) as u8)
+                    .collect();
+
+                (springs, groups, random.chance(0.5))
+            },
+            |case| {
+                let (springs, groups, copies) = record(case);
+                let groups: Vec<String> = groups.iter().map(|group| group.to_string()).collect();
+                let record = Record::parse(&format!("{springs} {}", groups.join(",")))
+                    .and_then(|record| record.unfolded(copies))
+                    .expect("a record");
+
+                record.candidates(0, 0, &mut HashMap::new())
+            },
+            |case| {
+                let (springs, groups, copies) = record(case);
+                let springs: Vec<char> = vec![springs; copies as usize].join("?").chars().collect();
+                let groups = groups.repeat(copies as usize);
+                let unknown: Vec<usize> = (0..springs.len())
+                    .filter(|index| springs[*index] == '?')
+                    .collect();
+
+                (0..1u32 << unknown.len())
+                    .filter(|arrangement| {
+                        let mut springs = springs.clone();
+                        unknown.iter().enumerate().for_each(|(bit, index)| {
+                            springs[*index] = match arrangement & (1 << bit) != 0 {
+                                true => '#',
+                                false => '.',
+                            }
+                        });
+
+                        springs
+                            .split(|spring| *spring == '.')
+                            .filter(|group| !group.is_empty())
+                            .map(|group| group.len() as u32)
+                            .eq(groups.iter().copied())
+                    })
+                    .count() as u64
+            },
+        );
+    }
+}
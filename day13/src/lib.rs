@@ -0,0 +1,216 @@
+use common::{Day, Example, ParseError, Solution, Solver};
+use grid::Grid;
+use rayon::prelude::*;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 13,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u32, ParseError> {
+        world.summarize(Field::clean_summary)
+    }
+
+    fn part_two(world: &World) -> Result<u32, ParseError> {
+        world.summarize(Field::smudge_summary)
+    }
+}
+
+pub struct World {
+    fields: Vec<Field>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            fields: input
+                .split("\n\n")
+                .map(Field::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Sums the summaries of the patterns, each of which needs a line of reflection.
+    fn summarize(&self, summary: fn(&Field) -> Option<u32>) -> Result<u32, ParseError> {
+        self.fields
+            .par_iter()
+            .enumerate()
+            .map(|(index, field)| {
+                summary(field).ok_or_else(|| {
+                    ParseError::unlocated(format!(
+                        "expected a line of reflection in pattern {}",
+                        index + 1
+                    ))
+                })
+            })
+            .sum()
+    }
+}
+
+/// A pattern, with every row and column as bits (`#` being 1).
+struct Field {
+    rows: Vec<u32>,
+    columns: Vec<u32>,
+}
+
+impl Field {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let pattern = Grid::parse(input, "expected # or .", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        if pattern.width().max(pattern.height()) > u32::BITS as usize {
+            return Err(ParseError::new(
+                input,
+                "expected at most 32 rows and columns",
+            ));
+        }
+
+        Ok(Self {
+            rows: pattern.rows().map(|row| Self::bits(row.iter())).collect(),
+            columns: pattern.columns().map(Self::bits).collect(),
+        })
+    }
+
+    fn bits<'a>(cells: impl Iterator<Item = &'a bool>) -> u32 {
+        cells.fold(0, |acc, rock| acc << 1 | *rock as u32)
+    }
+
+    fn clean_summary(&self) -> Option<u32> {
+        match Field::clean_reflection_at(&self.rows) {
+            None => Field::clean_reflection_at(&self.columns).map(|index| index + 1),
+            Some(index) => Some((index + 1) * 100),
+        }
+    }
+
+    fn smudge_summary(&self) -> Option<u32> {
+        match Field::smudge_reflection_at(&self.rows) {
+            None => Field::smudge_reflection_at(&self.columns).map(|index| index + 1),
+            Some(index) => Some((index + 1) * 100),
+        }
+    }
+
+    fn smudge_reflection_at(rows: &[u32]) -> Option<u32> {
+        let len = rows.len();
+        (0usize..len - 1)
+            .find(|i| {
+                let rows_to_check = (i + 1).min(len - i - 1);
+
+                let mut smudge_count = 0;
+
+                let result = (0..rows_to_check).all(|row| {
+                    let one_index = i - row;
+                    let other_index = i + 1 + row;
+
+                    let one = rows[one_index];
+                    let other = rows[other_index];
+
+                    let has_smudge = is_power_of_two(one ^ other);
+                    if has_smudge {
+                        smudge_count += 1;
+                    }
+
+                    (one == other || has_smudge) && smudge_count <= 1
+                });
+
+                result && smudge_count == 1
+            })
+            .map(|i| i as u32)
+    }
+
+    fn clean_reflection_at(rows: &[u32]) -> Option<u32> {
+        let len = rows.len();
+        (0usize..len - 1)
+            .find(|i| {
+                let rows_to_check = (i + 1).min(len - i - 1);
+
+                (0..rows_to_check).all(|row| {
+                    let one_index = i - row;
+                    let other_index = i + 1 + row;
+
+                    let one = rows[one_index];
+                    let other = rows[other_index];
+
+                    one == other
+                })
+            })
+            .map(|i| i as u32)
+    }
+}
+
+fn is_power_of_two(number: u32) -> bool {
+    (number != 0) && ((number & (number - 1)) == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn without_reflection() {
+        let world = World::parse("##\n\n#.#\n##.\n").unwrap();
+
+        assert_eq!(
+            Puzzle::part_one(&world).map_err(|error| error.message),
+            Err("expected a line of reflection in pattern 2".to_string())
+        );
+        assert!(Puzzle::part_two(&world).is_err());
+    }
+}
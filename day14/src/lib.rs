@@ -0,0 +1,335 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Write};
+
+use common::visual::{Colour, Picture, Pixel, Screen};
+use common::{Day, Example, ParseError, Part, Solution, Solver};
+use grid::{Grid, Position};
+
+use crate::Rock::{Cube, Empty, Round};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 14,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Platform;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Platform, ParseError> {
+        Platform::parse(input)
+    }
+
+    fn part_one(platform: &Platform) -> Result<usize, ParseError> {
+        Ok(platform.tilt(1).load())
+    }
+
+    fn part_two(platform: &Platform) -> Result<usize, ParseError> {
+        Ok(platform.cycle(1_000_000_000).load())
+    }
+}
+
+/// Draws the platform tilting: north for part one, spin cycles for part two, up to the first cycle
+/// that repeats an earlier one, and then the platform after all cycles.
+fn visualize(input: &str, part: Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let platform = Platform::parse(input)?;
+    screen.frame(platform.draw("start"));
+
+    match part {
+        Part::One => screen.frame(platform.tilt(1).draw("tilted north")),
+        Part::Two => {
+            let mut seen: HashSet<Grid<Rock>> = HashSet::new();
+            let mut cycled = platform.clone();
+            let mut cycles = 0;
+
+            while screen.is_animated() && seen.insert(cycled.rocks.clone()) {
+                cycled = cycled.cycle(1);
+                cycles += 1;
+                screen.frame(cycled.draw(&format!("{cycles} cycles")));
+            }
+
+            screen.frame(platform.cycle(1_000_000_000).draw("1000000000 cycles"));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Platform {
+    rocks: Grid<Rock>,
+}
+
+impl Platform {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rocks: Grid::parse(input, "expected #, O or .", Rock::parse)?,
+        })
+    }
+
+    fn cycle(&self, times: u64) -> Self {
+        self.tilt(times * 4)
+    }
+
+    fn tilt(&self, times: u64) -> Self {
+        let mut rocks = self.rocks.clone();
+
+        let mut cache: HashMap<Grid<Rock>, u64> = HashMap::new();
+
+        let mut time = 0;
+
+        while time < times {
+            if times.is_multiple_of(4) {
+                match cache.insert(rocks.clone(), time) {
+                    None => {}
+                    Some(old_time) => {
+                        let times_to_go = times - time;
+                        let repeat_every = time - old_time;
+                        let skip = (times_to_go / repeat_every) * repeat_every;
+                        time += skip;
+                    }
+                }
+            }
+
+            // skipping the repeats can take us all the way
+            if time < times {
+                rocks = Self::tilt_north(&rocks).rotated();
+
+                time += 1;
+            }
+        }
+
+        // ensure where back at the original position
+        (0..(4 - times % 4)).for_each(|_| {
+            rocks = rocks.rotated();
+        });
+
+        Self { rocks }
+    }
+
+    fn tilt_north(rocks: &Grid<Rock>) -> Grid<Rock> {
+        let mut tilted = rocks.clone();
+
+        (0..rocks.width()).for_each(|x| {
+            let mut free_y = 0;
+
+            (0..rocks.height()).for_each(|y| match rocks[Position { x, y }] {
+                Cube => free_y = y + 1,
+                Round => {
+                    tilted[Position { x, y }] = Empty;
+                    tilted[Position { x, y: free_y }] = Round;
+                    free_y += 1;
+                }
+                Empty => {}
+            })
+        });
+
+        tilted
+    }
+
+    fn load(&self) -> usize {
+        self.rocks
+            .iter()
+            .filter(|(_, rock)| **rock == Round)
+            .map(|(position, _)| self.rocks.height() - position.y)
+            .sum()
+    }
+}
+
+impl Platform {
+    /// The rocks, with a caption of what happened and the load.
+    fn draw(&self, caption: &str) -> Picture {
+        let rocks = self.rocks.map(|rock| match rock {
+            Round => Pixel::coloured('O', Colour::Yellow),
+            Cube => Pixel::coloured('#', Colour::Grey),
+            Empty => Pixel::new(' '),
+        });
+
+        rocks.picture(format!("{caption}, load: {}", self.load()))
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.rocks.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Rock {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl Rock {
+    fn parse(char: char) -> Option<Self> {
+        match char {
+            'O' => Some(Round),
+            '#' => Some(Cube),
+            '.' => Some(Empty),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(match self {
+            Round => 'O',
+            Cube => '#',
+            Empty => '.',
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn visualize_example() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE, Two, &mut screen).expect("example");
+        let frames = screen.finish();
+
+        assert!(frames[0].ends_with("start, load: 104\n"));
+        assert!(frames[1].ends_with("1 cycles, load: 87\n"));
+        assert!(frames
+            .last()
+            .expect("frames")
+            .ends_with("1000000000 cycles, load: 64\n"));
+    }
+
+    #[test]
+    fn tilt_differential() {
+        // rows of 0 for ., 1 for O and 2 for #, cut to the shortest row, and a number of cycles
+        let platform = |(rows, _): &(Vec<Vec<u8>>, u8)| {
+            let width = rows.iter().map(|row| row.len()).min().unwrap_or(0);
+            let rows: Vec<Vec<char>> = rows
+                .iter()
+                .map(|row| {
+                    row[..width]
+                        .iter()
+                        .map(|rock| ['.', 'O', '#'][*rock as usize % 3])
+                        .collect()
+                })
+                .collect();
+
+            match width {
+                0 => vec![vec!['.']],
+                _ => rows,
+            }
+        };
+        let text = |rows: &Vec<Vec<char>>| -> String {
+            rows.iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect()
+        };
+        // rolls the round rocks one step at a time, until none can move
+        let roll = |rows: &mut Vec<Vec<char>>, (dx, dy): (isize, isize)| {
+            let mut moved = true;
+
+            while moved {
+                moved = false;
+                (0..rows.len()).for_each(|y| {
+                    (0..rows[y].len()).for_each(|x| {
+                        let (to_x, to_y) = (x as isize + dx, y as isize + dy);
+                        if rows[y][x] == 'O'
+                            && to_y >= 0
+                            && to_x >= 0
+                            && rows
+                                .get(to_y as usize)
+                                .and_then(|row| row.get(to_x as usize))
+                                == Some(&'.')
+                        {
+                            rows[y][x] = '.';
+                            rows[to_y as usize][to_x as usize] = 'O';
+                            moved = true;
+                        }
+                    })
+                });
+            }
+        };
+        let shown = |text: &str| Platform::parse(text).expect("a platform").to_string();
+
+        common::testing::differential(
+            |random| {
+                let (width, height) = (random.between(1, 8), random.between(1, 8));
+                let rows = (0..height)
+                    .map(|_| (0..width).map(|_| random.below(3) as u8).collect())
+                    .collect();
+
+                (rows, random.below(100) as u8)
+            },
+            |case| {
+                let platform = Platform::parse(&text(&platform(case))).expect("a platform");
+
+                (
+                    platform.tilt(1).to_string(),
+                    platform.cycle(case.1 as u64).to_string(),
+                )
+            },
+            |case| {
+                let mut tilted = platform(case);
+                roll(&mut tilted, (0, -1));
+
+                let mut cycled = platform(case);
+                (0..case.1).for_each(|_| {
+                    [(0, -1), (-1, 0), (0, 1), (1, 0)]
+                        .into_iter()
+                        .for_each(|direction| roll(&mut cycled, direction))
+                });
+
+                (shown(&text(&tilted)), shown(&text(&cycled)))
+            },
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use crate::Operation::{Add, Subtract};
+use array_init::array_init;
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use std::fmt::{Display, Formatter, Write};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 15,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Operations;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<Operations, ParseError> {
+        Operations::parse(input)
+    }
+
+    fn part_one(operations: &Operations) -> Result<u32, ParseError> {
+        Ok(operations
+            .operations
+            .iter()
+            .map(|o| o.aoc_hash() as u32)
+            .sum())
+    }
+
+    fn part_two(operations: &Operations) -> Result<u32, ParseError> {
+        let mut boxes = Boxes::new();
+
+        operations.operations.iter().for_each(|operation| {
+            boxes.apply(operation);
+        });
+
+        Ok(boxes.focussing_power())
+    }
+}
+
+struct Boxes<'a> {
+    boxes: [Box<'a>; 256],
+}
+
+impl<'a> Boxes<'a> {
+    fn new() -> Self {
+        Self {
+            boxes: array_init(|_| Box::new()),
+        }
+    }
+
+    fn apply(&mut self, operation: &'a Operation) {
+        match operation {
+            Subtract(label) => self.subtract(label),
+            Add(label, length) => self.add(label, *length),
+        }
+    }
+
+    fn subtract(&mut self, label: &str) {
+        self.boxes
+            .iter_mut()
+            .for_each(|b| b.lenses.retain(|l| l.label.ne(label)))
+    }
+
+    fn add(&mut self, label: &'a str, length: u8) {
+        let b = self.boxes.get_mut(label.aoc_hash() as usize).unwrap();
+        let lens = Lens { label, length };
+
+        match b
+            .lenses
+            .iter()
+            .enumerate()
+            .position(|(_, l)| l.label.eq(label))
+        {
+            None => b.lenses.push(lens),
+            Some(index) => b.lenses[index] = lens,
+        }
+    }
+
+    fn focussing_power(&self) -> u32 {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(index, b)| b.focussing_power() * (index as u32 + 1))
+            .sum()
+    }
+}
+
+impl<'a> Display for Boxes<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.boxes.iter().enumerate().for_each(|(box_index, b)| {
+            if !b.lenses.is_empty() {
+                f.write_fmt(format_args!("Box {}: ", box_index)).unwrap();
+
+                b.lenses.iter().enumerate().for_each(|(lens_index, l)| {
+                    if lens_index != 0 {
+                        f.write_char(' ').unwrap();
+                    }
+                    f.write_fmt(format_args!("{}", l)).unwrap();
+                });
+                f.write_char('\n').unwrap()
+            }
+        });
+
+        Ok(())
+    }
+}
+
+pub struct Operations {
+    operations: Vec<Operation>,
+}
+
+impl Operations {
+    fn parse(str: &str) -> Result<Self, ParseError> {
+        Ok(Operations {
+            operations: str
+                .trim_end()
+                .split(',')
+                .map(Operation::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Display for Operations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.operations.iter().enumerate().for_each(|(index, o)| {
+            if index != 0 {
+                f.write_char(',').unwrap();
+            }
+
+            o.fmt(f).unwrap();
+        });
+
+        Ok(())
+    }
+}
+
+enum Operation {
+    Subtract(String),
+    Add(String, u8),
+}
+
+impl Operation {
+    fn parse(str: &str) -> Result<Self, ParseError> {
+        match str.strip_suffix('-') {
+            Some(label) => Ok(Subtract(label.to_string())),
+            None => {
+                let (label, length) = parse::split_once(str, "=")?;
+
+                Ok(Add(label.to_string(), parse::number(length)?))
+            }
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Subtract(label) => f.write_fmt(format_args!("{label}-")),
+            Add(label, length) => f.write_fmt(format_args!("{label}={length}")),
+        }
+    }
+}
+
+impl AocHash for Operation {
+    fn aoc_hash(&self) -> u8 {
+        self.to_string().aoc_hash()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Box<'a> {
+    lenses: Vec<Lens<'a>>,
+}
+
+impl<'a> Box<'a> {
+    fn new() -> Self {
+        Box { lenses: vec![] }
+    }
+
+    fn focussing_power(&self) -> u32 {
+        self.lenses
+            .iter()
+            .enumerate()
+            .map(|(index, lens)| (index + 1) as u32 * lens.length as u32)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Lens<'a> {
+    label: &'a str,
+    length: u8,
+}
+
+impl<'a> Display for Lens<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} {}", self.label, self.length))
+    }
+}
+
+trait AocHash {
+    fn aoc_hash(&self) -> u8;
+}
+
+impl AocHash for String {
+    fn aoc_hash(&self) -> u8 {
+        self.chars()
+            .fold(0u32, |acc, c| (acc + c as u8 as u32) * 17 % 256) as u8
+    }
+}
+
+impl AocHash for &str {
+    fn aoc_hash(&self) -> u8 {
+        self.chars()
+            .fold(0u32, |acc, c| (acc + c as u8 as u32) * 17 % 256) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn aoc_hash() {
+        assert_eq!("HASH".aoc_hash(), 52);
+        assert_eq!("rn=1".aoc_hash(), 30);
+        assert_eq!("rn".aoc_hash(), 0);
+        assert_eq!("".aoc_hash(), 0);
+    }
+
+    #[test]
+    fn operation_aoc_hash() {
+        assert_eq!(Operation::parse("rn=1").expect("add").aoc_hash(), 30);
+        assert_eq!(Operation::parse("cm-").expect("subtract").aoc_hash(), 253);
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use common::visual::{Colour, Picture, Pixel, Screen};
+use common::{Day, Example, ParseError, Part, Solution, Solver};
+use grid::Direction::{self, Down, Left, Right, Up};
+use grid::{Grid, Position};
+
+use Rotation::{Back, Forward};
+
+use crate::Orientation::{Horizontal, Vertical};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 16,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Contraption;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<Contraption, ParseError> {
+        Contraption::parse(input)
+    }
+
+    fn part_one(contraption: &Contraption) -> Result<usize, ParseError> {
+        Ok(contraption.energy(&Beam {
+            point: Position { x: 0, y: 0 },
+            direction: Right,
+        }))
+    }
+
+    fn part_two(contraption: &Contraption) -> Result<usize, ParseError> {
+        let max = contraption
+            .starts()
+            .map(|beam| contraption.energy(&beam))
+            .max()
+            .expect("energy");
+
+        Ok(max)
+    }
+}
+
+/// Draws the tiles getting energized, round by round, from the top left for part one and from the
+/// best start for part two.
+fn visualize(input: &str, part: Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let contraption = Contraption::parse(input)?;
+    let start = match part {
+        Part::One => Beam {
+            point: Position { x: 0, y: 0 },
+            direction: Right,
+        },
+        Part::Two => contraption
+            .starts()
+            .max_by_key(|beam| contraption.energy(beam))
+            .expect("a start"),
+    };
+
+    let animated = screen.is_animated();
+    let energized = contraption.energized(&start, |energized| {
+        if animated {
+            screen.frame(contraption.draw(energized));
+        }
+    });
+    screen.frame(contraption.draw(&energized));
+
+    Ok(())
+}
+
+pub struct Contraption {
+    items: Grid<Option<Item>>,
+}
+
+impl Contraption {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            items: Grid::parse(input, "expected ., |, -, \\ or /", |c| match c {
+                '.' => Some(None),
+                '|' => Some(Some(Item::Splitter(Vertical))),
+                '-' => Some(Some(Item::Splitter(Horizontal))),
+                '\\' => Some(Some(Item::Mirror(Back))),
+                '/' => Some(Some(Item::Mirror(Forward))),
+                _ => None,
+            })?,
+        })
+    }
+
+    /// The beams entering from every edge.
+    fn starts(&self) -> impl Iterator<Item = Beam> {
+        let width = self.items.width();
+        let height = self.items.height();
+
+        let vertical = (0..width).flat_map(move |x| {
+            vec![
+                Beam {
+                    point: Position { x, y: 0 },
+                    direction: Down,
+                },
+                Beam {
+                    point: Position { x, y: height - 1 },
+                    direction: Up,
+                },
+            ]
+        });
+        let horizontal = (0..height).flat_map(move |y| {
+            vec![
+                Beam {
+                    point: Position { x: width - 1, y },
+                    direction: Left,
+                },
+                Beam {
+                    point: Position { x: 0, y },
+                    direction: Right,
+                },
+            ]
+        });
+
+        vertical.chain(horizontal)
+    }
+
+    fn draw(&self, energized: &HashSet<Position>) -> Picture {
+        let tiles = self.items.map_with_position(|position, item| {
+            let char = match item {
+                None => '.',
+                Some(Item::Splitter(Vertical)) => '|',
+                Some(Item::Splitter(Horizontal)) => '-',
+                Some(Item::Mirror(Back)) => '\\',
+                Some(Item::Mirror(Forward)) => '/',
+            };
+
+            match (energized.contains(&position), item) {
+                (true, None) => Pixel::coloured('#', Colour::Yellow),
+                (true, Some(_)) => Pixel::coloured(char, Colour::Yellow),
+                (false, None) => Pixel::coloured(char, Colour::Grey),
+                (false, Some(_)) => Pixel::new(char),
+            }
+        });
+
+        tiles.picture(format!("energized: {}", energized.len()))
+    }
+
+    fn trace(&self, beam: &Beam) -> (Vec<Beam>, Vec<Position>) {
+        let mut point: Option<Position> = self.next(beam.point, &beam.direction);
+        let mut visited: Vec<Position> = vec![beam.point];
+
+        loop {
+            if let Some(current) = &point {
+                visited.push(*current);
+
+                match &self.items[*current] {
+                    None => {
+                        point = self.next(*current, &beam.direction);
+                    }
+                    Some(item) => {
+                        return (
+                            Self::beams_for_object(&beam.direction, *current, item),
+                            visited,
+                        )
+                    }
+                }
+            } else {
+                return (vec![], visited);
+            }
+        }
+    }
+
+    fn energy(&self, start: &Beam) -> usize {
+        self.energized(start, |_| {}).len()
+    }
+
+    /// The tiles the beam energizes; `round` sees them after every round of following the beams.
+    fn energized(
+        &self,
+        start: &Beam,
+        mut round: impl FnMut(&HashSet<Position>),
+    ) -> HashSet<Position> {
+        let mut active_beams: Vec<Beam> = match &self.items[start.point] {
+            None => vec![*start],
+            Some(object) => Self::beams_for_object(&start.direction, start.point, object),
+        };
+
+        let mut processed_beams = HashSet::new();
+        processed_beams.extend(&active_beams);
+
+        let mut visited: HashSet<Position> = HashSet::new();
+
+        while !active_beams.is_empty() {
+            let traces = &active_beams
+                .iter()
+                .map(|beam| self.trace(beam))
+                .collect::<Vec<(Vec<Beam>, Vec<Position>)>>();
+
+            let mut new_beams: HashSet<Beam> = HashSet::new();
+            traces.iter().for_each(|(beams, points)| {
+                visited.extend(points);
+                new_beams.extend(beams);
+            });
+
+            active_beams = new_beams.difference(&processed_beams).copied().collect();
+
+            processed_beams.extend(&active_beams);
+            round(&visited);
+        }
+
+        visited
+    }
+
+    fn beams_for_object(direction: &Direction, current: Position, item: &Item) -> Vec<Beam> {
+        match &item {
+            Item::Mirror(rotation) => vec![Beam {
+                point: current,
+                direction: rotation.rotate(direction),
+            }],
+            Item::Splitter(orientation) => orientation
+                .split(direction)
+                .iter()
+                .map(|next_direction| Beam {
+                    point: current,
+                    direction: *next_direction,
+                })
+                .collect(),
+        }
+    }
+
+    fn next(&self, point: Position, direction: &Direction) -> Option<Position> {
+        self.items.step(point, *direction)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Item {
+    Mirror(Rotation),
+    Splitter(Orientation),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Rotation {
+    Back,
+    Forward,
+}
+
+impl Rotation {
+    fn rotate(&self, direction: &Direction) -> Direction {
+        let clockwise = match self {
+            Back => direction.is_horizontal(),
+            Forward => direction.is_vertical(),
+        };
+
+        match clockwise {
+            true => direction.clockwise(),
+            false => direction.counter_clockwise(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    fn split(&self, direction: &Direction) -> Vec<Direction> {
+        match self {
+            Horizontal => match direction {
+                Left | Right => vec![*direction],
+                Up | Down => vec![Left, Right],
+            },
+            Vertical => match direction {
+                Left | Right => vec![Up, Down],
+                Up | Down => vec![*direction],
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Beam {
+    point: Position,
+    direction: Direction,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn visualize_example() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE, Two, &mut screen).expect("example");
+        let frames = screen.finish();
+
+        assert!(frames.len() > 1);
+        assert!(frames.last().expect("frames").ends_with("energized: 51\n"));
+    }
+}
@@ -0,0 +1,276 @@
+use crate::Part::{One, Two};
+use common::search;
+use common::visual::{Colour, Pixel, Screen};
+use common::{Day, Example, ParseError, Solution, Solver};
+use grid::Direction::{self, Down, Right};
+use grid::{Grid, Position, Turn};
+use std::fmt::Debug;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE_1: &str = include_str!("example-1.txt");
+const EXAMPLE_2: &str = include_str!("example-2.txt");
+
+pub const DAY: Day = Day {
+    number: 17,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE_1,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[
+            Example {
+                name: "example-1",
+                input: EXAMPLE_1,
+                solve: Puzzle::two,
+            },
+            Example {
+                name: "example-2",
+                input: EXAMPLE_2,
+                solve: Puzzle::two,
+            },
+        ],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = HeatMap;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> Result<HeatMap, ParseError> {
+        HeatMap::parse(input)
+    }
+
+    fn part_one(heat_map: &HeatMap) -> Result<u32, ParseError> {
+        heat_map.minimum_temperature(&One)
+    }
+
+    fn part_two(heat_map: &HeatMap) -> Result<u32, ParseError> {
+        heat_map.minimum_temperature(&Two)
+    }
+}
+
+/// Draws the path of the crucible losing the least heat, block by block.
+fn visualize(input: &str, part: common::Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let heat_map = HeatMap::parse(input)?;
+    let (path, temperature) = heat_map.optimal_path(match part {
+        common::Part::One => &One,
+        common::Part::Two => &Two,
+    })?;
+
+    let frames = match screen.is_animated() {
+        true => 1..=path.len(),
+        false => path.len()..=path.len(),
+    };
+    frames.for_each(|length| {
+        let driven = &path[..length];
+        let lost: u32 = driven
+            .iter()
+            .skip(1)
+            .map(|crucible| heat_map.values[crucible.position])
+            .sum();
+
+        screen.frame(
+            heat_map
+                .draw(driven)
+                .picture(format!("heat lost: {lost} of {temperature}")),
+        );
+    });
+
+    Ok(())
+}
+
+pub struct HeatMap {
+    values: Grid<u32>,
+}
+
+/// Which crucible drives: the crucible for part one, the ultra crucible for part two.
+enum Part {
+    One,
+    Two,
+}
+
+impl HeatMap {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            values: Grid::parse(input, "expected a digit", |c| c.to_digit(10))?,
+        })
+    }
+
+    /// The heat values, coloured from cool to hot, with the path of the crucibles on it.
+    fn draw(&self, path: &[Crucible]) -> Grid<Pixel> {
+        let mut pixels = self.values.map(|value| {
+            let heat = match value {
+                0..=3 => Colour::Blue,
+                4..=6 => Colour::Magenta,
+                _ => Colour::Red,
+            };
+            Pixel::coloured(char::from_digit(*value, 10).unwrap_or('?'), heat)
+        });
+
+        path.iter().for_each(|crucible| {
+            let arrow = match crucible.orientation {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            };
+            pixels[crucible.position] = Pixel::coloured(arrow, Colour::Green);
+        });
+        if let Some(crucible) = path.last() {
+            pixels[crucible.position].colour = Some(Colour::White);
+        }
+
+        pixels
+    }
+
+    fn next_crucibles(&self, crucible: &Crucible, part: &Part) -> Vec<(Crucible, u32)> {
+        [Turn::Left, Turn::Straight, Turn::Right]
+            .iter()
+            .filter_map(|turn| self.next_crucible(crucible, turn, part))
+            .map(|next_crucible| {
+                let temperature = self.values[next_crucible.position];
+                (next_crucible, temperature)
+            })
+            .collect()
+    }
+
+    fn next_crucible(&self, crucible: &Crucible, turn: &Turn, part: &Part) -> Option<Crucible> {
+        let orientation = crucible.orientation.turn(*turn);
+        match part {
+            One => match self.next_position(&crucible.position, &orientation) {
+                None => None,
+                Some(position) => match (turn, crucible.straight_count) {
+                    (Turn::Straight, 2) => None,
+                    (Turn::Straight, _) => Some(Crucible {
+                        position,
+                        orientation,
+                        straight_count: crucible.straight_count + 1,
+                    }),
+                    (_, _) => Some(Crucible {
+                        position,
+                        orientation,
+                        straight_count: 0,
+                    }),
+                },
+            },
+            Two => match self.next_position(&crucible.position, &orientation) {
+                None => None,
+                Some(position) => match (turn, crucible.straight_count) {
+                    (Turn::Straight, 10) => None,
+                    (Turn::Straight, _) => Some(Crucible {
+                        position,
+                        orientation,
+                        straight_count: crucible.straight_count + 1,
+                    }),
+                    (_, 4..) => Some(Crucible {
+                        position,
+                        orientation,
+                        straight_count: 1,
+                    }),
+                    _ => None,
+                },
+            },
+        }
+    }
+
+    fn next_position(&self, position: &Position, orientation: &Direction) -> Option<Position> {
+        self.values.step(*position, *orientation)
+    }
+
+    fn minimum_temperature(&self, part: &Part) -> Result<u32, ParseError> {
+        Ok(self.optimal_path(part)?.1)
+    }
+
+    /// The crucibles on the way losing the least heat, from the start to the end, and the heat lost.
+    /// Fails when the crucible can't get to the end, e.g. on a map too small for the ultra crucible.
+    fn optimal_path(&self, part: &Part) -> Result<(Vec<Crucible>, u32), ParseError> {
+        let end = Position {
+            x: self.values.width() - 1,
+            y: self.values.height() - 1,
+        };
+        let starts = [Right, Down].map(|orientation| Crucible {
+            position: Position { x: 0, y: 0 },
+            orientation,
+            straight_count: 0,
+        });
+        // every block on the way to the end costs at least this
+        let coolest = self
+            .values
+            .iter()
+            .map(|(_, value)| *value)
+            .min()
+            .unwrap_or(0);
+
+        search::a_star_path(
+            starts,
+            |crucible| self.next_crucibles(crucible, part),
+            |crucible| crucible.position.manhattan_distance(end) as u32 * coolest,
+            |crucible| {
+                crucible.position == end
+                    && match part {
+                        One => true,
+                        Two => crucible.straight_count > 3,
+                    }
+            },
+        )
+        .ok_or_else(|| ParseError::unlocated("expected a way for the crucible to the end"))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Crucible {
+    position: Position,
+    orientation: Direction,
+    straight_count: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn visualize_example() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE_1, One, &mut screen).expect("example");
+        let frames = screen.finish();
+
+        assert!(frames
+            .last()
+            .expect("frames")
+            .ends_with("heat lost: 102 of 102\n"));
+    }
+}
@@ -0,0 +1,322 @@
+use std::ops::RangeInclusive;
+
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+use grid::Direction::{self, Down, Left, Right, Up};
+use grid::Point;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 18,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Instructions;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        Instructions::parse(input)
+    }
+
+    fn part_one(instructions: &Instructions) -> Result<u64, ParseError> {
+        Ok(DigPlan::new(instructions.lines.iter().map(|line| &line.step)).filled())
+    }
+
+    fn part_two(instructions: &Instructions) -> Result<u64, ParseError> {
+        Ok(DigPlan::new(instructions.lines.iter().map(|line| &line.colour_step)).filled())
+    }
+}
+
+/// Every line of the dig plan, read both as written and from its colour.
+pub struct Instructions {
+    lines: Vec<Instruction>,
+}
+
+impl Instructions {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Instruction> = input
+            .lines()
+            .map(Instruction::parse)
+            .collect::<Result<_, _>>()?;
+
+        // read either way, the trench must get back to the start, which a plan cut short doesn't
+        let closed = |steps: Vec<&Step>| {
+            steps.iter().try_fold(Point::default(), |point, step| {
+                point.step_by(step.direction, step.count)
+            }) == Some(Point::default())
+        };
+        match closed(lines.iter().map(|line| &line.step).collect())
+            && closed(lines.iter().map(|line| &line.colour_step).collect())
+        {
+            true => Ok(Self { lines }),
+            false => Err(ParseError::new(
+                &input[input.len()..],
+                "expected the trench to end where it starts",
+            )),
+        }
+    }
+}
+
+struct Instruction {
+    step: Step,
+    colour_step: Step,
+}
+
+impl Instruction {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let segments: Vec<&str> = input.split(' ').collect();
+        let [direction, count, colour] = segments[..] else {
+            return Err(ParseError::new(
+                input,
+                "expected a direction, count and colour",
+            ));
+        };
+
+        Ok(Self {
+            step: Step {
+                direction: parse_direction(direction)?,
+                count: parse::number(count)?,
+            },
+            colour_step: Step::parse_colour(colour)?,
+        })
+    }
+}
+
+struct DigPlan {
+    points: Vec<Point<i64>>,
+}
+
+impl DigPlan {
+    fn new<'a>(steps: impl Iterator<Item = &'a Step>) -> Self {
+        let mut points = vec![Point::default()];
+
+        steps.for_each(|step| {
+            let last = points.last().expect("point");
+            points.push(
+                last.step_by(step.direction, step.count)
+                    .expect("a point within i64"),
+            );
+        });
+
+        Self { points }
+    }
+
+    fn filled(&self) -> u64 {
+        let mut ys: Vec<i64> = self.points.iter().map(|p| p.y).collect();
+        ys.sort();
+        ys.dedup();
+
+        let mut ranges = self.ranges_on_y(ys[0]);
+        let mut result = ranges
+            .iter()
+            .map(|r| (r.end() - r.start() + 1) as u64)
+            .sum();
+
+        ys.windows(2).for_each(|window| {
+            let previous_y = window[0];
+            let current_y = window[1];
+
+            let xs_on_previous_y: Vec<i64> = ranges
+                .iter()
+                .flat_map(|range| vec![*range.start(), *range.end()])
+                .collect();
+            let xs_on_current_y = self.xs_on_y(current_y);
+
+            let mut all_xs: Vec<i64> = Vec::new();
+            all_xs.extend(&xs_on_previous_y);
+            all_xs.extend(&xs_on_current_y);
+            all_xs.sort();
+            all_xs.dedup();
+
+            let split_previous = Self::split(&ranges, &all_xs);
+            let split_current = Self::split(&self.ranges_on_y(current_y), &all_xs);
+
+            let mut new_ranges: Vec<RangeInclusive<i64>> = Vec::new();
+            new_ranges.extend(split_previous.clone());
+            new_ranges.extend(split_current.clone());
+            new_ranges.sort_by_key(|r| *r.start());
+            new_ranges.dedup();
+
+            let diff_y = (current_y - previous_y) as u64;
+            let rows_above = diff_y - 1;
+            let surface_above = rows_above * Self::surface_or_ranges(&ranges);
+
+            let surface_at_row = Self::surface_or_ranges(&Self::merge(&new_ranges));
+
+            let surface = surface_above + surface_at_row;
+            result += surface;
+
+            let mut split_ranges = new_ranges
+                .iter()
+                .filter(|candidate| {
+                    let exist_in_previous_row = split_previous.contains(candidate);
+                    let exist_in_current_row = split_current.contains(candidate);
+
+                    // only keep ranges that are either in this row (they start here) or that
+                    // exist only in the previous row (they're untouched. Otherwise (they exist
+                    // in both) it marks the end.
+                    exist_in_previous_row ^ exist_in_current_row
+                })
+                .cloned()
+                .collect::<Vec<RangeInclusive<i64>>>();
+            split_ranges.sort_by_key(|r| *r.start());
+
+            ranges = Self::merge(&split_ranges);
+        });
+
+        result
+    }
+
+    fn surface_or_ranges(merged_new_ranges: &[RangeInclusive<i64>]) -> u64 {
+        merged_new_ranges
+            .iter()
+            .map(|r| (r.end() - r.start() + 1) as u64)
+            .sum::<u64>()
+    }
+
+    fn split(ranges: &[RangeInclusive<i64>], all_xs: &[i64]) -> Vec<RangeInclusive<i64>> {
+        ranges
+            .iter()
+            .flat_map(|range| {
+                all_xs
+                    .iter()
+                    .filter(|x| range.contains(x))
+                    .collect::<Vec<&i64>>()
+                    .windows(2)
+                    .map(|x| *x[0]..=*x[1])
+                    .collect::<Vec<RangeInclusive<i64>>>()
+            })
+            .collect()
+    }
+
+    fn merge(split_ranges: &[RangeInclusive<i64>]) -> Vec<RangeInclusive<i64>> {
+        let mut merged_ranges: Vec<RangeInclusive<i64>> = Vec::new();
+        split_ranges
+            .iter()
+            .for_each(|range| match merged_ranges.is_empty() {
+                true => {
+                    merged_ranges.push(range.clone());
+                }
+                false => {
+                    let len = merged_ranges.len();
+                    let last = &merged_ranges[len - 1];
+                    match last.end() == range.start() {
+                        true => {
+                            merged_ranges[len - 1] = *last.start()..=*range.end();
+                        }
+                        false => merged_ranges.push(range.clone()),
+                    }
+                }
+            });
+        merged_ranges
+    }
+
+    fn xs_on_y(&self, on_y: i64) -> Vec<i64> {
+        let mut xs_on_y: Vec<i64> = self
+            .points
+            .iter()
+            .filter(|p| p.y == on_y)
+            .map(|p| p.x)
+            .collect();
+        xs_on_y.sort();
+        xs_on_y.dedup();
+
+        xs_on_y
+    }
+
+    fn ranges_on_y(&self, on_y: i64) -> Vec<RangeInclusive<i64>> {
+        self.xs_on_y(on_y)
+            .chunks(2)
+            .map(|xs| xs[0]..=xs[1])
+            .collect::<Vec<RangeInclusive<i64>>>()
+    }
+}
+
+struct Step {
+    direction: Direction,
+    count: i64,
+}
+
+impl Step {
+    fn parse_colour(colour: &str) -> Result<Self, ParseError> {
+        let hex = colour
+            .strip_prefix("(#")
+            .and_then(|colour| colour.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| ParseError::new(colour, "expected a colour like (#70c710)"))?;
+
+        Ok(Self {
+            direction: match &hex[5..] {
+                "0" => Right,
+                "1" => Down,
+                "2" => Left,
+                "3" => Up,
+                _ => return Err(ParseError::new(&hex[5..], "expected 0, 1, 2 or 3")),
+            },
+            count: i64::from_str_radix(&hex[..5], 16).expect("hex digits"),
+        })
+    }
+}
+
+fn parse_direction(input: &str) -> Result<Direction, ParseError> {
+    match input {
+        "U" => Ok(Up),
+        "D" => Ok(Down),
+        "L" => Ok(Left),
+        "R" => Ok(Right),
+        _ => Err(ParseError::new(input, "expected U, D, L or R")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+}
@@ -0,0 +1,524 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use common::generate::Random;
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+use Condition::Fallback;
+
+use crate::Comparison::{GreaterThan, SmallerThan};
+use crate::Condition::Compare;
+use crate::Parameter::{A, M, S, X};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 19,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        Ok(world
+            .parts
+            .iter()
+            .filter(|part| world.eval(part) == Decision::Accept)
+            .map(|part| part.rating())
+            .sum())
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        Ok(world
+            .accepted_part_domains(&PartDomain::new())
+            .iter()
+            .map(|part_domain| part_domain.combinations())
+            .sum())
+    }
+}
+
+type RuleSetName = String;
+
+/// Generates random workflows starting at `in`, and random parts; the size is the number of both.
+/// Workflows only send parts on to workflows after them, so no part is sent around in circles.
+fn generate(random: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let names: Vec<String> = (0..size)
+        .map(|index| match index {
+            0 => "in".to_string(),
+            _ => {
+                // 1 -> a, 26 -> z, 27 -> aa, ...; "in" has its own number
+                let mut name = String::new();
+                let mut number = index + usize::from(index >= 9 * 26 + 14);
+                while number > 0 {
+                    name.insert(0, (b'a' + ((number - 1) % 26) as u8) as char);
+                    number = (number - 1) / 26;
+                }
+                name
+            }
+        })
+        .collect();
+
+    // a workflow after this one, or accept or reject right away
+    let target = |random: &mut Random, index: usize| match random.below(size - index) {
+        0 => random.choose(&["A", "R"]).to_string(),
+        offset => names[index + offset].clone(),
+    };
+
+    let mut workflows: Vec<String> = (0..size)
+        .map(|index| {
+            let mut rules: Vec<String> = (0..random.between(1, 3))
+                .map(|_| {
+                    let parameter = random.choose(&['x', 'm', 'a', 's']);
+                    let comparison = random.choose(&['<', '>']);
+                    let value = random.between(1, 4000);
+
+                    format!("{parameter}{comparison}{value}:{}", target(random, index))
+                })
+                .collect();
+            rules.push(target(random, index));
+
+            format!("{}{{{}}}\n", names[index], rules.join(","))
+        })
+        .collect();
+    random.shuffle(&mut workflows);
+
+    let parts = (0..size).map(|_| {
+        let mut rating = || random.between(1, 4000);
+        format!(
+            "{{x={},m={},a={},s={}}}\n",
+            rating(),
+            rating(),
+            rating(),
+            rating()
+        )
+    });
+
+    workflows
+        .into_iter()
+        .chain(["\n".to_string()])
+        .chain(parts)
+        .collect()
+}
+
+pub struct World {
+    rule_sets: HashMap<RuleSetName, RuleSet>,
+    parts: Vec<Part>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (rule_sets, parts) = parse::split_once(input, "\n\n")?;
+
+        let rule_sets: HashMap<RuleSetName, RuleSet> = rule_sets
+            .lines()
+            .map(|line| RuleSet::parse(line).map(|rule_set| (rule_set.name.clone(), rule_set)))
+            .collect::<Result<_, _>>()?;
+        let parts: Vec<Part> = parts.lines().map(Part::parse).collect::<Result<_, _>>()?;
+
+        if !rule_sets.contains_key("in") {
+            return Err(ParseError::new(input, "expected a workflow named in"));
+        }
+        if let Some(unknown) = rule_sets
+            .values()
+            .flat_map(|rule_set| &rule_set.rules)
+            .find_map(|rule| match &rule.action {
+                Action::Move(name) if !rule_sets.contains_key(name) => Some(name),
+                _ => None,
+            })
+        {
+            return Err(ParseError::new(unknown, "expected a known workflow"));
+        }
+
+        Ok(Self { rule_sets, parts })
+    }
+
+    fn eval(&self, part: &Part) -> Decision {
+        self.eval_rule(part, &self.rule_sets["in"])
+    }
+
+    fn eval_rule(&self, part: &Part, rule_set: &RuleSet) -> Decision {
+        match rule_set.eval(part) {
+            Action::Done(decision) => decision,
+            Action::Move(rule_set_name) => self.eval_rule(part, &self.rule_sets[&rule_set_name]),
+        }
+    }
+
+    fn accepted_part_domains(&self, part_domain: &PartDomain) -> Vec<PartDomain> {
+        self.accepted_part_domains_rule(part_domain, &"in".to_string())
+    }
+
+    fn accepted_part_domains_rule(
+        &self,
+        part_domain: &PartDomain,
+        rule_set_name: &RuleSetName,
+    ) -> Vec<PartDomain> {
+        let rule_set = &self.rule_sets[rule_set_name];
+
+        let mut remaining_domain = Some(part_domain.clone());
+        let mut results: Vec<PartDomain> = rule_set
+            .rules
+            .iter()
+            .flat_map(|rule| match remaining_domain.clone() {
+                None => vec![],
+                Some(remaining) => {
+                    let (kept_domain, rule_domain) = rule.condition.split(&remaining);
+                    remaining_domain = kept_domain;
+
+                    match rule_domain {
+                        None => vec![],
+                        Some(some_rule_domain) => match &rule.action {
+                            Action::Done(decision) => match decision {
+                                Decision::Accept => vec![some_rule_domain],
+                                Decision::Reject => vec![],
+                            },
+                            Action::Move(next_rule_set_name) => self
+                                .accepted_part_domains_rule(&some_rule_domain, next_rule_set_name),
+                        },
+                    }
+                }
+            })
+            .collect();
+
+        if let Some(remaining) = remaining_domain {
+            results.push(remaining)
+        }
+
+        results
+    }
+}
+
+struct RuleSet {
+    name: RuleSetName,
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (name, rules) = parse::split_once(input, "{")?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::new(rules, "expected rules ending with }"))?;
+
+        Ok(RuleSet {
+            name: name.to_string(),
+            rules: rules
+                .split(',')
+                .map(Rule::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn eval(&self, part: &Part) -> Action {
+        self.rules
+            .iter()
+            .find(|rule| rule.condition.matches(part))
+            .expect("a rule to match")
+            .action
+            .clone()
+    }
+}
+
+#[derive(Clone)]
+struct Rule {
+    condition: Condition,
+    action: Action,
+}
+
+impl Rule {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        match input.split_once(':') {
+            None => Ok(Rule {
+                condition: Fallback,
+                action: Action::parse(input),
+            }),
+            Some((condition, action)) => Ok(Rule {
+                condition: Compare(CompareCondition::parse(condition)?),
+                action: Action::parse(action),
+            }),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Action {
+    Done(Decision),
+    Move(RuleSetName),
+}
+
+#[derive(Clone, Eq, PartialEq)]
+enum Decision {
+    Accept,
+    Reject,
+}
+
+impl Action {
+    fn parse(input: &str) -> Self {
+        match input {
+            "A" => Action::Done(Decision::Accept),
+            "R" => Action::Done(Decision::Reject),
+            _ => Action::Move(input.to_string()),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Condition {
+    Compare(CompareCondition),
+    Fallback,
+}
+
+impl Condition {
+    fn matches(&self, part: &Part) -> bool {
+        match self {
+            Compare(compare_condition) => compare_condition.matches(part),
+            Fallback => true,
+        }
+    }
+
+    fn split(&self, part_domain: &PartDomain) -> (Option<PartDomain>, Option<PartDomain>) {
+        match self {
+            Compare(compare_condition) => compare_condition.split(part_domain),
+            Fallback => (None, Some(part_domain.clone())),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CompareCondition {
+    parameter: Parameter,
+    comparison: Comparison,
+    value: u64,
+}
+
+impl CompareCondition {
+    fn matches(&self, part: &Part) -> bool {
+        match self.comparison {
+            GreaterThan => part.values[&self.parameter] > self.value,
+            SmallerThan => part.values[&self.parameter] < self.value,
+        }
+    }
+
+    fn split(&self, part_domain: &PartDomain) -> (Option<PartDomain>, Option<PartDomain>) {
+        let range = &part_domain.domains[&self.parameter];
+        let start = *range.start();
+        let end = *range.end();
+
+        match self.comparison {
+            GreaterThan => {
+                let unchanged = start > self.value;
+                let removed = end < self.value;
+                let reduced = range.contains(&self.value);
+
+                match (unchanged, removed, reduced) {
+                    (true, false, false) => (None, Some(part_domain.clone())),
+                    (false, true, false) => (Some(part_domain.clone()), None),
+                    (false, false, true) => (
+                        Some(part_domain.with_parameter(&self.parameter, start..=self.value)),
+                        Some(part_domain.with_parameter(&self.parameter, self.value + 1..=end)),
+                    ),
+                    _ => panic!("unexpected"),
+                }
+            }
+            SmallerThan => {
+                let unchanged = end < self.value;
+                let removed = start > self.value;
+                let reduced = range.contains(&self.value);
+
+                match (unchanged, removed, reduced) {
+                    (true, false, false) => (None, Some(part_domain.clone())),
+                    (false, true, false) => (Some(part_domain.clone()), None),
+                    (false, false, true) => (
+                        Some(part_domain.with_parameter(&self.parameter, self.value..=end)),
+                        Some(part_domain.with_parameter(&self.parameter, start..=self.value - 1)),
+                    ),
+                    _ => panic!("unexpected"),
+                }
+            }
+        }
+    }
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let comparison_index = input
+            .find(['<', '>'])
+            .ok_or_else(|| ParseError::new(input, "expected < or >"))?;
+        let parameter = Parameter::parse(&input[0..comparison_index])?;
+        let comparison = Comparison::parse(&input[comparison_index..=comparison_index])?;
+        let value: u64 = parse::number(&input[comparison_index + 1..])?;
+
+        Ok(CompareCondition {
+            parameter,
+            comparison,
+            value,
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+enum Parameter {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Parameter {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        match input {
+            "x" => Ok(Parameter::X),
+            "m" => Ok(Parameter::M),
+            "a" => Ok(Parameter::A),
+            "s" => Ok(Parameter::S),
+            _ => Err(ParseError::new(input, "expected x, m, a or s")),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Comparison {
+    GreaterThan,
+    SmallerThan,
+}
+
+impl Comparison {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        match input {
+            "<" => Ok(SmallerThan),
+            ">" => Ok(GreaterThan),
+            _ => Err(ParseError::new(input, "expected < or >")),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct PartDomain {
+    domains: HashMap<Parameter, RangeInclusive<u64>>,
+}
+
+impl PartDomain {
+    fn new() -> Self {
+        Self {
+            domains: [X, M, A, S]
+                .iter()
+                .map(|param| (param.clone(), 1..=4000))
+                .collect(),
+        }
+    }
+
+    fn with_parameter(&self, parameter: &Parameter, new_value: RangeInclusive<u64>) -> Self {
+        Self {
+            domains: self
+                .domains
+                .iter()
+                .map(|(key, current_value)| {
+                    (
+                        key.clone(),
+                        match key == parameter {
+                            true => new_value.clone(),
+                            false => current_value.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn combinations(&self) -> u64 {
+        self.domains
+            .values()
+            .map(|domain| domain.end() - domain.start() + 1)
+            .product::<u64>()
+    }
+}
+
+struct Part {
+    values: HashMap<Parameter, u64>,
+}
+
+impl Part {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let values: HashMap<Parameter, u64> = input
+            .strip_prefix('{')
+            .and_then(|input| input.strip_suffix('}'))
+            .ok_or_else(|| ParseError::new(input, "expected a part like {x=1,m=2,a=3,s=4}"))?
+            .split(',')
+            .map(|segment| {
+                let (parameter, value) = parse::split_once(segment, "=")?;
+
+                Ok((Parameter::parse(parameter)?, parse::number(value)?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        match values.len() {
+            4 => Ok(Self { values }),
+            _ => Err(ParseError::new(input, "expected x, m, a and s")),
+        }
+    }
+
+    fn rating(&self) -> u64 {
+        self.values.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 10, 100]);
+        common::testing::generated(&DAY, Two, &[1, 10, 100]);
+    }
+}
@@ -0,0 +1,477 @@
+use common::generate::Random;
+use common::math::{self, Congruence, Unsolvable};
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use State::On;
+
+use crate::Kind::{Broadcaster, Conjunction, FlipFlop};
+use crate::Pulse::{High, Low};
+use crate::State::Off;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE_1: &str = include_str!("example-1.txt");
+const EXAMPLE_2: &str = include_str!("example-2.txt");
+
+pub const DAY: Day = Day {
+    number: 20,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[
+            Example {
+                name: "example-1",
+                input: EXAMPLE_1,
+                solve: Puzzle::one,
+            },
+            Example {
+                name: "example-2",
+                input: EXAMPLE_2,
+                solve: Puzzle::one,
+            },
+        ],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<u64, ParseError> {
+        Ok(world.push(1000, |_, _| false).0)
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        let rx_sender_name = world
+            .machines
+            .iter()
+            .find(|machine| machine.outgoing.contains(&"rx".to_string()))
+            .ok_or_else(|| ParseError::unlocated("expected a module sending to rx"))?
+            .name();
+
+        let rx_input_names: Vec<String> = world
+            .machines
+            .iter()
+            .filter(|machine| machine.outgoing.contains(&rx_sender_name))
+            .map(|machine| machine.name())
+            .collect();
+
+        if rx_input_names.is_empty() {
+            return Err(ParseError::unlocated(format!(
+                "expected a module sending to {rx_sender_name}"
+            )));
+        }
+
+        let cycles: Vec<(u64, Congruence)> = rx_input_names
+            .par_iter()
+            .map(|rx_input_name| {
+                let rx_input = world
+                    .machines
+                    .iter()
+                    .position(|machine| machine.name() == *rx_input_name)
+                    .expect("rx input to exist");
+
+                // the presses at which the rx input sends a high pulse: the first two tell the cycle
+                let mut high_presses: Vec<u64> = Vec::new();
+                world.push(u64::MAX, |circuit, press| {
+                    if circuit.current[rx_input].contains(&High)
+                        && high_presses.last() != Some(&press)
+                    {
+                        high_presses.push(press);
+                    }
+
+                    high_presses.len() == 2
+                });
+
+                let first = high_presses[0];
+                (first, Congruence::repeating(first, high_presses[1] - first))
+            })
+            .collect();
+
+        let all_started = cycles.iter().map(|(first, _)| *first).max().unwrap_or(0);
+        let cycles: Vec<Congruence> = cycles.iter().map(|(_, cycle)| *cycle).collect();
+
+        math::first_common(&cycles, all_started).map_err(|unsolvable| match unsolvable {
+            Unsolvable::Never => {
+                ParseError::unlocated("expected the inputs of rx to send high pulses together")
+            }
+            Unsolvable::Overflow => ParseError::unlocated(
+                "expected the presses until the inputs of rx send high pulses together to fit in \
+                 64 bits",
+            ),
+        })
+    }
+}
+
+/// The bits of the counters in a generated input.
+const COUNTER_BITS: usize = 12;
+
+/// The primes the counts of generated counters are made of, each at most once. The presses until
+/// all counters reset together divide the product of these primes, so part two's answer fits a u64
+/// for any number of counters.
+const COUNT_PRIMES: [usize; 14] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Generates modules like the puzzle's: binary counters of flip-flops, each with a conjunction
+/// that resets it at a random count, all feeding a conjunction that sends to rx. The size is the
+/// number of counters; part two already takes seconds for a few of them.
+fn generate(random: &mut Random, size: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+    let counters = size.max(1);
+
+    // odd and with the highest bit set, so the first and last flip-flops report to the reset
+    let counts: Vec<usize> = (1 << (COUNTER_BITS - 1)..1 << COUNTER_BITS)
+        .filter(|count| count % 2 == 1)
+        .filter(|count| {
+            let rest = COUNT_PRIMES.iter().try_fold(*count, |rest, prime| {
+                match (rest % prime, rest / prime % prime) {
+                    (0, 0) => None,
+                    (0, _) => Some(rest / prime),
+                    _ => Some(rest),
+                }
+            });
+            rest == Some(1)
+        })
+        .collect();
+
+    (0..counters).for_each(|counter| {
+        let count = *random.choose(&counts);
+        let flip_flop = |bit: usize| format!("c{counter}f{bit}");
+        let reset = format!("c{counter}r");
+        let set = |bit: usize| count & (1 << bit) != 0;
+
+        (0..COUNTER_BITS).for_each(|bit| {
+            let outgoing: Vec<String> = (bit + 1 < COUNTER_BITS)
+                .then(|| flip_flop(bit + 1))
+                .into_iter()
+                .chain(set(bit).then(|| reset.clone()))
+                .collect();
+            lines.push(format!("%{} -> {}", flip_flop(bit), outgoing.join(", ")));
+        });
+
+        let outgoing: Vec<String> = (0..COUNTER_BITS)
+            .filter(|bit| *bit == 0 || !set(*bit))
+            .map(flip_flop)
+            .chain([format!("c{counter}i")])
+            .collect();
+        lines.push(format!("&{reset} -> {}", outgoing.join(", ")));
+        lines.push(format!("&c{counter}i -> out"));
+    });
+
+    lines.push("&out -> rx".to_string());
+    random.shuffle(&mut lines);
+
+    let starts: Vec<String> = (0..counters)
+        .map(|counter| format!("c{counter}f0"))
+        .collect();
+    lines.insert(0, format!("broadcaster -> {}", starts.join(", ")));
+
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// The modules and how they are wired, which pushing the button leaves unchanged.
+pub struct World {
+    machines: Vec<Machine>,
+}
+
+/// What pushing the button changes: each module's memory and the pulses it is sending, by the
+/// index of the module in the world.
+struct Circuit {
+    memories: Vec<Memory>,
+    current: Vec<Vec<Pulse>>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let machines: Vec<Machine> = input
+            .lines()
+            .map(Machine::parse)
+            .collect::<Result<_, _>>()?;
+
+        match machines.iter().any(|machine| machine.kind == Broadcaster) {
+            true => Ok(World { machines }),
+            false => Err(ParseError::new(input, "expected a broadcaster")),
+        }
+    }
+
+    fn push(&self, times: u64, mut or: impl FnMut(&Circuit, u64) -> bool) -> (u64, u64) {
+        let (mut total_low, mut total_high) = (0u64, 0u64);
+        let mut time = 0;
+
+        let mut circuit = Circuit {
+            memories: self
+                .machines
+                .iter()
+                .map(|machine| machine.kind.memory())
+                .collect(),
+            current: vec![vec![]; self.machines.len()],
+        };
+        let broadcaster = self
+            .machines
+            .iter()
+            .position(|machine| machine.kind == Broadcaster)
+            .expect("broadcaster");
+
+        while time < times && !or(&circuit, time) {
+            common::progress::check();
+            time += 1;
+            // println!("time: {}", time);
+
+            circuit.current[broadcaster] = vec![Low];
+
+            let (mut added_low, mut added_high) = (1u64, 0u64);
+
+            while added_low != 0 || added_high != 0 {
+                total_low += added_low;
+                total_high += added_high;
+
+                let pulses: Vec<(MachineName, MachineName, Vec<Pulse>)> = self
+                    .machines
+                    .iter()
+                    .zip(&circuit.current)
+                    .flat_map(|(machine, current)| {
+                        machine
+                            .outgoing
+                            .iter()
+                            .map(|name| (machine.name(), name.clone(), current.clone()))
+                    })
+                    .collect();
+
+                // pulses.iter().for_each(|(from, to, pulse)| match pulse {
+                //     None => {}
+                //     Some(pulse) => {
+                //         println!("{} -{}-> {}", from, pulse, to);
+                //     }
+                // });
+
+                circuit.current = self
+                    .machines
+                    .iter()
+                    .zip(circuit.memories.iter_mut())
+                    .map(|(machine, memory)| {
+                        let name = machine.name();
+
+                        machine.process(
+                            memory,
+                            pulses
+                                .iter()
+                                .filter(|(_, to, _)| *to == name)
+                                .map(|(from, _, pulse)| (from.clone(), pulse.clone()))
+                                .collect(),
+                        )
+                    })
+                    .collect();
+
+                // self.machines.iter().for_each(|machine| {
+                //     println!("{}", machine);
+                // });
+
+                (added_low, added_high) = pulses.iter().fold(
+                    (0, 0),
+                    |(outer_added_low, outer_added_high), (_, _, inner_pulses)| {
+                        let (inner_added_low, inner_added_high) = inner_pulses.iter().fold(
+                            (0, 0),
+                            |(inner_added_low, inner_added_high), inner_pulse| match inner_pulse {
+                                Low => (inner_added_low + 1, inner_added_high),
+                                High => (inner_added_low, inner_added_high + 1),
+                            },
+                        );
+
+                        (
+                            outer_added_low + inner_added_low,
+                            outer_added_high + inner_added_high,
+                        )
+                    },
+                );
+
+                if or(&circuit, time) {
+                    break;
+                }
+            }
+        }
+
+        (total_low * total_high, time)
+    }
+}
+
+type MachineName = String;
+
+struct Machine {
+    kind: Kind,
+    outgoing: Vec<String>,
+}
+
+impl Machine {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (kind, outgoing) = parse::split_once(input, " -> ")?;
+
+        Ok(Self {
+            kind: Kind::parse(kind)?,
+            outgoing: outgoing.split(", ").map(|s| s.to_string()).collect(),
+        })
+    }
+
+    fn name(&self) -> String {
+        match &self.kind {
+            Broadcaster => "broadcaster".to_string(),
+            FlipFlop(name) => name.to_string(),
+            Conjunction(name) => name.to_string(),
+        }
+    }
+
+    /// The pulses sent on receiving the given ones, updating the memory of the machine.
+    fn process(
+        &self,
+        memory: &mut Memory,
+        pulses_by_machine: HashMap<MachineName, Vec<Pulse>>,
+    ) -> Vec<Pulse> {
+        let mut next = vec![];
+
+        match memory {
+            Memory::Broadcaster => {}
+            Memory::FlipFlop(state) => {
+                let previous = *state;
+                pulses_by_machine.iter().for_each(|(_, pulses)| {
+                    pulses.iter().for_each(|pulse| match pulse {
+                        High => {}
+                        Low => {
+                            *state = previous.flip();
+
+                            next.push(match state {
+                                On => High,
+                                Off => Low,
+                            });
+                        }
+                    })
+                });
+            }
+            Memory::Conjunction(state) => {
+                if state.is_empty() {
+                    pulses_by_machine.keys().for_each(|key| {
+                        state.insert(key.clone(), Low);
+                    });
+                }
+
+                pulses_by_machine.iter().for_each(|(machine_name, pulses)| {
+                    pulses.iter().for_each(|pulse| {
+                        state.insert(machine_name.clone(), *pulse);
+
+                        let all_high = state.iter().all(|(_, pulse)| pulse == &High);
+
+                        next.push(match all_high {
+                            true => Low,
+                            false => High,
+                        });
+                    });
+                });
+            }
+        };
+
+        next
+    }
+}
+
+#[derive(Eq, PartialEq)]
+enum Kind {
+    Broadcaster,
+    FlipFlop(String),
+    Conjunction(String),
+}
+
+impl Kind {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        match input.chars().next() {
+            Some('%') => Ok(FlipFlop(input[1..input.len()].to_string())),
+            Some('&') => Ok(Conjunction(input[1..input.len()].to_string())),
+            _ if input == "broadcaster" => Ok(Broadcaster),
+            _ => Err(ParseError::new(
+                input,
+                "expected %name, &name or broadcaster",
+            )),
+        }
+    }
+
+    fn memory(&self) -> Memory {
+        match self {
+            Broadcaster => Memory::Broadcaster,
+            FlipFlop(_) => Memory::FlipFlop(Off),
+            Conjunction(_) => Memory::Conjunction(HashMap::new()),
+        }
+    }
+}
+
+enum Memory {
+    Broadcaster,
+    FlipFlop(State),
+    Conjunction(HashMap<MachineName, Pulse>),
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum State {
+    On,
+    Off,
+}
+
+impl State {
+    fn flip(&self) -> Self {
+        match self {
+            On => Off,
+            Off => On,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Pulse {
+    High,
+    Low,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    #[ignore = "slow, run with cargo test --release -- --ignored"]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 2]);
+    }
+
+    #[test]
+    #[ignore = "slow, run with cargo test --release -- --ignored"]
+    fn generated_two() {
+        common::testing::generated(&DAY, Two, &[1, 2]);
+    }
+}
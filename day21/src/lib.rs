@@ -0,0 +1,316 @@
+use common::search;
+use common::visual::{Colour, Pixel, Screen};
+use common::{Day, Example, ParseError, Part, Solution, Solver};
+use grid::{Grid, Position};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 21,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: |input| Ok(World::parse(input)?.reached(6).to_string()),
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = usize;
+    type Two = u64;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<usize, ParseError> {
+        Ok(world.reached(64))
+    }
+
+    fn part_two(world: &World) -> Result<u64, ParseError> {
+        Ok(reached_repeated(world, 26501365))
+    }
+}
+
+/// Draws the garden plots reached step by step: the ones the elf can end on after 64 steps for part
+/// one, and for part two all plots the elf can reach on the map, by whether it takes an even or odd
+/// number of steps.
+fn visualize(input: &str, part: Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let world = World::parse(input)?;
+    let distances: Vec<(Position, usize)> = world.distances(world.start).collect();
+    let farthest = distances.last().map(|(_, steps)| *steps).unwrap_or(0);
+
+    let last = match part {
+        Part::One => 64,
+        Part::Two => farthest,
+    };
+    let frames = match screen.is_animated() {
+        true => 0..=last,
+        false => last..=last,
+    };
+
+    frames.for_each(|steps| {
+        let reached: Vec<&(Position, usize)> = distances
+            .iter()
+            .take_while(|(_, distance)| *distance <= steps)
+            .filter(|(_, distance)| match part {
+                Part::One => distance % 2 == steps % 2,
+                Part::Two => true,
+            })
+            .collect();
+
+        let mut pixels = world.map.map(|rock| match rock {
+            true => Pixel::coloured('#', Colour::Grey),
+            false => Pixel::new('.'),
+        });
+        reached.iter().for_each(|(position, distance)| {
+            pixels[*position] = match distance % 2 {
+                0 => Pixel::coloured('O', Colour::Green),
+                _ => Pixel::coloured('O', Colour::Cyan),
+            }
+        });
+        pixels[world.start].char = 'S';
+
+        screen.frame(pixels.picture(format!("steps: {steps}, plots: {}", reached.len())));
+    });
+
+    Ok(())
+}
+
+fn reached_repeated(world: &World, number_of_steps: usize) -> u64 {
+    let size = world.map.width();
+
+    let steps_to_leave_center = ((size - 1) / 2) as u64;
+    let blocks_in_between = (number_of_steps as u64 - steps_to_leave_center) / size as u64;
+
+    let center_bottom = Position {
+        x: (size - 1) / 2,
+        y: size - 1,
+    };
+    let center_top = Position {
+        x: (size - 1) / 2,
+        y: 0,
+    };
+    let center_right = Position {
+        x: size - 1,
+        y: (size - 1) / 2,
+    };
+    let center_left = Position {
+        x: 0,
+        y: (size - 1) / 2,
+    };
+    let points_on_axis: u64 = [center_bottom, center_top, center_right, center_left]
+        .map(|point| world.reached_from_point(point, size - 1, false) as u64)
+        .iter()
+        .sum();
+
+    let point_up_left = Position { x: 0, y: 0 };
+    let point_up_right = Position { x: size - 1, y: 0 };
+    let point_down_left = Position { x: 0, y: size - 1 };
+    let point_down_right = Position {
+        x: size - 1,
+        y: size - 1,
+    };
+
+    let one_quarter_filled_points: u64 = [
+        point_up_left,
+        point_up_right,
+        point_down_left,
+        point_down_right,
+    ]
+    .map(|point| world.reached_from_point(point, ((size - 1) / 2) - 1, true) as u64)
+    .iter()
+    .sum();
+
+    let three_quarter_filled_points: u64 = [
+        point_up_left,
+        point_up_right,
+        point_down_left,
+        point_down_right,
+    ]
+    .map(|point| world.reached_from_point(point, (size - 1) / 2 + size - 1, false) as u64)
+    .iter()
+    .sum();
+
+    let completely_filled_even = world.reached_from_point(world.start, size, false) as u64;
+    let completely_filled_odd = world.reached_from_point(world.start, size, true) as u64;
+
+    let max_number_of_completed_blocks_on_row = (blocks_in_between - 1) * 2 + 1;
+
+    let max_even_rows = max_number_of_completed_blocks_on_row / 2;
+    let max_odd_rows = max_number_of_completed_blocks_on_row / 2 + 1;
+
+    let filled_odd_blocks = max_odd_rows + triangle_number(max_odd_rows - 1) * 2;
+    let filled_even_blocks = max_even_rows + triangle_number(max_even_rows - 1) * 2;
+
+    let number_of_one_quarter_sets = blocks_in_between;
+    let number_of_three_quarter_sets = blocks_in_between - 1;
+
+    completely_filled_even * filled_even_blocks
+        + completely_filled_odd * filled_odd_blocks
+        + points_on_axis
+        + one_quarter_filled_points * number_of_one_quarter_sets
+        + three_quarter_filled_points * number_of_three_quarter_sets
+}
+
+fn triangle_number(number: u64) -> u64 {
+    (number * (number + 1)) / 2
+}
+
+pub struct World {
+    start: Position,
+    /// Where the rocks are.
+    map: Grid<bool>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let plots = Grid::parse(input, "expected ., # or S", |c| match c {
+            '.' | '#' | 'S' => Some(c),
+            _ => None,
+        })?;
+
+        let start = plots
+            .find(|c| *c == 'S')
+            .ok_or_else(|| ParseError::new(input, "expected a start S"))?;
+
+        Ok(Self {
+            start,
+            map: plots.map(|c| *c == '#'),
+        })
+    }
+
+    /// The plots reachable from a point, with the least number of steps to get there, nearest first.
+    fn distances(&self, from: Position) -> impl Iterator<Item = (Position, usize)> + '_ {
+        search::bfs(from, |position| {
+            self.map
+                .neighbours(*position)
+                .filter(|next| !self.map[*next])
+                .collect::<Vec<Position>>()
+        })
+    }
+
+    fn reached(&self, number_of_steps: usize) -> usize {
+        self.reached_from_point(self.start, number_of_steps, true)
+    }
+
+    fn reached_from_point(&self, from: Position, number_of_steps: usize, odd: bool) -> usize {
+        self.distances(from)
+            .take_while(|(_, steps)| *steps <= number_of_steps)
+            .filter(|(reached_point, _)| match odd {
+                true => reached_point.y % 2 == reached_point.x % 2,
+                false => reached_point.y % 2 != reached_point.x % 2,
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn visualize_example() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE, One, &mut screen).expect("example");
+        let frames = screen.finish();
+
+        assert_eq!(frames.len(), 65);
+        assert!(frames[6].ends_with("steps: 6, plots: 16\n"));
+    }
+
+    #[test]
+    fn reached_repeated_differential() {
+        // a map like the input: square, an odd number of steps to its edge from the start in the
+        // centre, with no rocks in the row and column of the start or on the edges, and an even
+        // number of maps to walk through after leaving the first one
+        let garden = |(size, rocks, repeats): &(u8, Vec<(u8, u8)>, u8)| {
+            let size = [7, 11, 15][*size as usize % 3];
+            let centre = size / 2;
+            let open = |x: usize, y: usize| {
+                x == centre
+                    || y == centre
+                    || x.is_multiple_of(size - 1)
+                    || y.is_multiple_of(size - 1)
+            };
+            let mut map = vec![vec!['.'; size]; size];
+
+            rocks
+                .iter()
+                .map(|(x, y)| (*x as usize % size, *y as usize % size))
+                .filter(|(x, y)| !open(*x, *y))
+                .for_each(|(x, y)| map[y][x] = '#');
+            map[centre][centre] = 'S';
+
+            (map, 2 * (*repeats as usize % 3 + 1) * size + centre)
+        };
+
+        common::testing::differential(
+            |random| {
+                let rocks = (0..random.below(40))
+                    .map(|_| (random.below(15) as u8, random.below(15) as u8))
+                    .collect();
+
+                (random.below(3) as u8, rocks, random.below(3) as u8)
+            },
+            |case| {
+                let (map, steps) = garden(case);
+                let input: String = map
+                    .iter()
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect();
+
+                reached_repeated(&World::parse(&input).expect("a garden"), steps)
+            },
+            |case| {
+                let (map, steps) = garden(case);
+                let size = map.len() as isize;
+                let centre = size / 2;
+                let rock = |(x, y): (isize, isize)| {
+                    map[y.rem_euclid(size) as usize][x.rem_euclid(size) as usize] == '#'
+                };
+
+                search::bfs((centre, centre), |(x, y)| {
+                    [(x + 1, *y), (x - 1, *y), (*x, y + 1), (*x, y - 1)]
+                        .into_iter()
+                        .filter(|position| !rock(*position))
+                        .collect::<Vec<(isize, isize)>>()
+                })
+                .take_while(|(_, distance)| *distance <= steps)
+                .filter(|(_, distance)| distance % 2 == steps % 2)
+                .count() as u64
+            },
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use common::generate::Random;
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+use std::collections::HashSet;
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 22,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: Some(generate),
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = World;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<World, ParseError> {
+        World::parse(input)
+    }
+
+    fn part_one(world: &World) -> Result<usize, ParseError> {
+        Ok(world.apply_gravity().0.removeable_count())
+    }
+
+    fn part_two(world: &World) -> Result<usize, ParseError> {
+        let world = world.apply_gravity().0;
+
+        Ok(world
+            .blocks
+            .iter()
+            .map(|block| world.blocks_fallen_removing_block(block))
+            .sum())
+    }
+}
+
+/// The width and depth of the area that generated bricks fall in.
+const AREA: usize = 10;
+/// The longest generated brick.
+const LONGEST: usize = 4;
+
+/// Generates bricks that don't overlap, floating above the ground in a small area, each along a
+/// single axis. The size is the number of bricks.
+fn generate(random: &mut Random, size: usize) -> String {
+    let mut occupied: HashSet<(usize, usize, usize)> = HashSet::new();
+    let mut input = String::new();
+    let mut placed = 0;
+
+    while placed < size {
+        let length = random.between(1, LONGEST) - 1;
+        let (dx, dy, dz) = *random.choose(&[(1, 0, 0), (0, 1, 0), (0, 0, 1)]);
+        let from = Point {
+            x: random.below(AREA - dx * length),
+            y: random.below(AREA - dy * length),
+            z: random.between(1, 2 * size),
+        };
+        let cubes: Vec<(usize, usize, usize)> = (0..=length)
+            .map(|step| (from.x + dx * step, from.y + dy * step, from.z + dz * step))
+            .collect();
+
+        if cubes.iter().all(|cube| !occupied.contains(cube)) {
+            occupied.extend(cubes.iter());
+            placed += 1;
+            input.push_str(&format!(
+                "{},{},{}~{},{},{}\n",
+                from.x,
+                from.y,
+                from.z,
+                from.x + dx * length,
+                from.y + dy * length,
+                from.z + dz * length
+            ));
+        }
+    }
+
+    input
+}
+
+pub struct World {
+    blocks: Vec<Block>,
+}
+
+impl World {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            blocks: input.lines().map(Block::parse).collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn apply_gravity(&self) -> (Self, usize) {
+        let mut dropped = 0usize;
+        let mut height_map = HeightMap::from(self);
+
+        let mut new_blocks = self.blocks.clone();
+        new_blocks.sort_by_key(|block| block.from.z);
+        new_blocks.iter_mut().for_each(|block| {
+            if height_map.drop(block) {
+                dropped += 1
+            }
+        });
+
+        (Self { blocks: new_blocks }, dropped)
+    }
+
+    fn blocks_fallen_removing_block(&self, block_to_remove: &Block) -> usize {
+        let world = Self {
+            blocks: self
+                .blocks
+                .clone()
+                .into_iter()
+                .filter(|block| block != block_to_remove)
+                .collect(),
+        };
+
+        world.apply_gravity().1
+    }
+
+    fn removeable_count(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|candidate| {
+                (
+                    candidate,
+                    self.blocks
+                        .iter()
+                        .filter(|supported_by_candidate_candidate| {
+                            candidate.is_supporting(supported_by_candidate_candidate)
+                        })
+                        .collect::<Vec<&Block>>(),
+                )
+            })
+            .map(|(candidate, supported_by_candidate_vec)| {
+                (
+                    candidate,
+                    supported_by_candidate_vec
+                        .iter()
+                        .map(|supported_by_candidate| {
+                            (
+                                *supported_by_candidate,
+                                self.blocks
+                                    .iter()
+                                    .filter(|supporting_supported_by_candidate_candidate| {
+                                        supporting_supported_by_candidate_candidate
+                                            .is_supporting(supported_by_candidate)
+                                    })
+                                    .collect::<Vec<&Block>>(),
+                            )
+                        })
+                        .collect::<Vec<(&Block, Vec<&Block>)>>(),
+                )
+            })
+            .filter(|(_, supported_by_candidate_vec)| {
+                let all_supported_blocks_supported_by_another_block = supported_by_candidate_vec
+                    .iter()
+                    .all(|(_, supporting_supported)| supporting_supported.len() > 1);
+
+                all_supported_blocks_supported_by_another_block
+            })
+            .count()
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct Point {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Point {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let values: Vec<usize> = input
+            .split(',')
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+
+        match values[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(ParseError::new(input, "expected x,y,z")),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+struct Block {
+    from: Point,
+    to: Point,
+}
+
+impl Block {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (from, to) = parse::split_once(input, "~")?;
+        let block = Self {
+            from: Point::parse(from)?,
+            to: Point::parse(to)?,
+        };
+
+        match block.from.x <= block.to.x && block.from.y <= block.to.y && block.from.z <= block.to.z
+        {
+            true => Ok(block),
+            false => Err(ParseError::new(to, "expected an end at or after the start")),
+        }
+    }
+
+    fn drop(&mut self, height: usize) {
+        self.from.z -= height;
+        self.to.z -= height;
+    }
+
+    fn z_size(&self) -> usize {
+        self.to.z - self.from.z + 1
+    }
+
+    fn is_supporting(&self, supported: &Block) -> bool {
+        self.to.z + 1 == supported.from.z
+            && self.from.x <= supported.to.x
+            && self.to.x >= supported.from.x
+            && self.from.y <= supported.to.y
+            && self.to.y >= supported.from.y
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Size {
+    x: usize,
+    y: usize,
+}
+
+struct HeightMap {
+    size: Size,
+    map: Vec<usize>,
+}
+
+impl HeightMap {
+    fn from(world: &World) -> Self {
+        let size = world
+            .blocks
+            .iter()
+            .fold(Size { x: 0, y: 0 }, |acc, block| Size {
+                x: acc.x.max(block.to.x + 1),
+                y: acc.y.max(block.to.y + 1),
+            });
+
+        Self {
+            size,
+            map: (0..(size.x * size.y)).map(|_| 0).collect(),
+        }
+    }
+
+    fn height_at_point(&self, x: usize, y: usize) -> usize {
+        self.map[y * self.size.x + x]
+    }
+
+    fn height_at_block(&self, block: &Block) -> usize {
+        (block.from.y..=block.to.y).fold(0, |acc, y| {
+            acc.max(
+                (block.from.x..=block.to.x).fold(0, |acc, x| self.height_at_point(x, y).max(acc)),
+            )
+        })
+    }
+
+    fn set_height_at_point(&mut self, x: usize, y: usize, height: usize) {
+        self.map[y * self.size.x + x] = height
+    }
+
+    fn set_height_at_block(&mut self, block: &Block, height: usize) {
+        (block.from.y..=block.to.y).for_each(|y| {
+            (block.from.x..=block.to.x).for_each(|x| self.set_height_at_point(x, y, height))
+        })
+    }
+
+    fn drop(&mut self, block: &mut Block) -> bool {
+        let current_height = self.height_at_block(block);
+        let new_height = current_height + block.z_size();
+        self.set_height_at_block(block, new_height);
+
+        let dropping = block.from.z - current_height - 1;
+
+        block.drop(dropping);
+
+        dropping != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn generated() {
+        common::testing::generated(&DAY, One, &[1, 10, 100]);
+        common::testing::generated(&DAY, Two, &[1, 10, 100]);
+    }
+}
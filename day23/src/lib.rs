@@ -0,0 +1,454 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use common::search::{self, NoRoute};
+use common::visual::{Colour, Pixel, Screen};
+use common::{Day, Example, ParseError, Solution, Solver};
+use grid::Direction::{self, Down, Left, Right, Up};
+use grid::{Grid, Position};
+
+use crate::Part::{One, Two};
+use crate::Tile::{Forrest, Path, Slope};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 23,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: Some(visualize),
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::one,
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: Puzzle::two,
+        }],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = TrailMap;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> Result<TrailMap, ParseError> {
+        TrailMap::from_str(input)
+    }
+
+    fn part_one(trail_map: &TrailMap) -> Result<usize, ParseError> {
+        trail_map.longest_trail_length(One)
+    }
+
+    fn part_two(trail_map: &TrailMap) -> Result<usize, ParseError> {
+        trail_map.longest_trail_length(Two)
+    }
+}
+
+enum Part {
+    One,
+    Two,
+}
+
+/// Draws the longest hike, trail by trail.
+fn visualize(input: &str, part: common::Part, screen: &mut Screen) -> Result<(), ParseError> {
+    let trail_map = TrailMap::from_str(input)?;
+    let (length, trails) = trail_map.longest_trail(match part {
+        common::Part::One => One,
+        common::Part::Two => Two,
+    })?;
+
+    let frames = match screen.is_animated() {
+        true => 0..=trails.len(),
+        false => trails.len()..=trails.len(),
+    };
+    frames.for_each(|count| {
+        let mut pixels = trail_map.tiles.map(|tile| match tile {
+            Path => Pixel::new(' '),
+            Forrest => Pixel::coloured('#', Colour::Green),
+            Slope(Up) => Pixel::new('^'),
+            Slope(Down) => Pixel::new('v'),
+            Slope(Right) => Pixel::new('>'),
+            Slope(Left) => Pixel::new('<'),
+        });
+        let hiked: Vec<&Position> = trails[..count]
+            .iter()
+            .flat_map(|trail| trail.iter())
+            .collect();
+        hiked.iter().for_each(|position| {
+            pixels[**position] = Pixel::coloured('O', Colour::Yellow);
+        });
+
+        screen.frame(pixels.picture(format!("steps: {} of {length}", hiked.len())));
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+struct Trail {
+    from: Position,
+    to: Position,
+    size: usize,
+    /// The tiles from just after `from` up to `to`.
+    tiles: Vec<Position>,
+}
+
+#[derive(Clone)]
+pub struct TrailMap {
+    tiles: Grid<Tile>,
+}
+
+impl TrailMap {
+    fn get_tile(&self, point: &Position) -> Tile {
+        self.tiles[*point]
+    }
+
+    fn start(&self) -> Position {
+        self.tiles
+            .find(|tile| *tile == Path)
+            .expect("a starting point")
+    }
+
+    fn end(&self) -> Position {
+        Position {
+            x: self.tiles.width() - 2,
+            y: self.tiles.height() - 1,
+        }
+    }
+
+    fn points_from(
+        &self,
+        point: &Position,
+        from: Option<&Direction>,
+    ) -> Vec<(Direction, Position)> {
+        Direction::ALL
+            .into_iter()
+            .filter(|candidate| match from {
+                None => true,
+                Some(from) => match self.get_tile(point) {
+                    Path => candidate.opposite() != *from,
+                    Forrest => false,
+                    Slope(direction) => candidate == &direction,
+                },
+            })
+            .flat_map(|direction| {
+                self.tiles
+                    .step(*point, direction)
+                    .map(|point| (direction, point))
+            })
+            .filter(|(to, point)| match self.get_tile(point) {
+                Forrest => false,
+                Slope(direction) => direction != to.opposite(),
+                Path => true,
+            })
+            .collect()
+    }
+
+    fn longest_trail_length(&self, part: Part) -> Result<usize, ParseError> {
+        Ok(self.longest_trail(part)?.0)
+    }
+
+    /// The length of the longest hike, and the tiles of the trails on it in order of hiking. Fails
+    /// when no trail leads from the start to the end.
+    fn longest_trail(&self, part: Part) -> Result<(usize, Vec<Vec<Position>>), ParseError> {
+        let trails = self.trails_from_start();
+
+        // number the junctions, so the search can keep the visited ones in a bit mask: at most 64
+        let mut junctions: Vec<Position> = trails
+            .iter()
+            .flat_map(|trail| [trail.from, trail.to])
+            .collect();
+        junctions.sort_by_key(|point| self.tiles.index_of(*point));
+        junctions.dedup();
+        let number = |point: &Position| junctions.iter().position(|junction| junction == point);
+        let no_trail = || ParseError::unlocated("expected a trail from the start to the end");
+
+        let mut edges: Vec<Vec<(usize, u64)>> = vec![vec![]; junctions.len()];
+        trails.iter().for_each(|trail| {
+            let (from, to) = (
+                number(&trail.from).expect("a junction"),
+                number(&trail.to).expect("a junction"),
+            );
+
+            edges[from].push((to, trail.size as u64));
+            if let Two = part {
+                edges[to].push((from, trail.size as u64));
+            }
+        });
+
+        let (start, end) = (
+            number(&self.start()).ok_or_else(no_trail)?,
+            number(&self.end()).ok_or_else(no_trail)?,
+        );
+        let (length, route) = search::longest_route(start, end, |junction| {
+            // exploring all routes takes long: stop when cancelled
+            common::progress::check();
+            edges[junction].iter().copied()
+        })
+        .map_err(|no_route| match no_route {
+            NoRoute::Unreachable => no_trail(),
+            NoRoute::TooManyNodes => ParseError::unlocated("expected at most 64 junctions"),
+        })?;
+
+        let hiked = route
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (&junctions[pair[0]], &junctions[pair[1]]);
+                let trail = trails
+                    .iter()
+                    .filter(|trail| {
+                        (trail.from == *from && trail.to == *to)
+                            || (trail.from == *to && trail.to == *from)
+                    })
+                    .max_by_key(|trail| trail.size)
+                    .expect("a trail between the junctions");
+
+                match trail.from == *from {
+                    true => trail.tiles.clone(),
+                    // hiking back: from the tile before the end up to the start
+                    false => trail.tiles[..trail.tiles.len() - 1]
+                        .iter()
+                        .rev()
+                        .copied()
+                        .chain([trail.from])
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok((length as usize, hiked))
+    }
+
+    fn trails_from_start(&self) -> Vec<Trail> {
+        let mut trails: HashSet<Trail> = HashSet::new();
+        let mut explored: HashSet<Position> = HashSet::new();
+        let mut to_explore = vec![self.start()];
+
+        while let Some(point) = to_explore.pop() {
+            if point == self.end() || !explored.insert(point) {
+                continue;
+            }
+
+            self.find_trails_from_point(&point)
+                .into_iter()
+                .for_each(|trail| {
+                    to_explore.push(trail.to);
+                    trails.insert(trail);
+                });
+        }
+
+        let mut result = Vec::from_iter(trails);
+        result.sort_by_key(|trail| self.tiles.index_of(trail.from));
+        result
+    }
+
+    fn find_trails_from_point(&self, point: &Position) -> Vec<Trail> {
+        self.points_from(point, None)
+            .iter()
+            .filter_map(|(direction, start)| self.find_trail_in_direction(point, start, direction))
+            .collect()
+    }
+
+    /// The trail from `origin` through `start` to the next junction: the tile after a slope, a
+    /// path branching off, or the end. None when the trail leads to a dead end.
+    fn find_trail_in_direction(
+        &self,
+        origin: &Position,
+        start: &Position,
+        to: &Direction,
+    ) -> Option<Trail> {
+        let mut size = 1;
+
+        let mut current_point = *start;
+        let mut current_to = *to;
+        let mut tiles = vec![];
+
+        loop {
+            tiles.push(current_point);
+
+            match (size == 1, self.get_tile(&current_point.clone())) {
+                (_, Path) | (true, Slope(_)) => {
+                    let points_from = self.points_from(&current_point, Some(&current_to));
+
+                    match (self.end() == current_point, &points_from[..]) {
+                        // the end of the map, or a path branching off
+                        (true, _) | (false, [_, _, ..]) => {
+                            return Some(Trail {
+                                from: *origin,
+                                to: current_point,
+                                size,
+                                tiles,
+                            })
+                        }
+                        (false, [(next_to, next_point)]) => {
+                            current_to = *next_to;
+                            current_point = *next_point;
+                            size += 1;
+                        }
+                        (false, []) => return None,
+                    }
+                }
+                (_, Forrest) => return None,
+                (false, Slope(_)) => {
+                    let points_from = self.points_from(&current_point, Some(&current_to));
+                    let (_, end_of_trail) = points_from.first()?;
+                    tiles.push(*end_of_trail);
+
+                    return Some(Trail {
+                        from: *origin,
+                        to: *end_of_trail,
+                        size: size + 1,
+                        tiles,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for TrailMap {
+    type Err = ParseError;
+
+    /// Also checks that the map is surrounded by forest, but for a single path in the top row, the
+    /// start, and the end next to the bottom right corner, so hiking can't leave the map.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse(s, "expected #, ., ^, v, > or <", Tile::parse)?;
+        let lines: Vec<&str> = s.lines().map(|line| line.trim_end_matches('\r')).collect();
+        let (width, height) = (tiles.width(), tiles.height());
+
+        if width < 3 || height < 2 {
+            return Err(ParseError::new(
+                s,
+                "expected a map of at least 3 by 2 tiles",
+            ));
+        }
+        if tiles.row(0).iter().filter(|tile| **tile == Path).count() != 1
+            || tiles
+                .row(0)
+                .iter()
+                .any(|tile| !matches!(tile, Path | Forrest))
+        {
+            return Err(ParseError::new(
+                lines[0],
+                "expected forest with a single path",
+            ));
+        }
+        if tiles.row(height - 1).iter().enumerate().any(|(x, tile)| {
+            *tile
+                != match x == width - 2 {
+                    true => Path,
+                    false => Forrest,
+                }
+        }) {
+            return Err(ParseError::new(
+                lines[height - 1],
+                "expected forest with a path next to the corner",
+            ));
+        }
+        if let Some(edge) = lines[1..height - 1]
+            .iter()
+            .flat_map(|line| [&line[..1], &line[line.len() - 1..]])
+            .find(|edge| *edge != "#")
+        {
+            return Err(ParseError::new(edge, "expected forest at the edge"));
+        }
+
+        Ok(Self { tiles })
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Path,
+    Forrest,
+    Slope(Direction),
+}
+
+impl Tile {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Forrest),
+            '.' => Some(Path),
+            '^' => Some(Slope(Up)),
+            'v' => Some(Slope(Down)),
+            '>' => Some(Slope(Right)),
+            '<' => Some(Slope(Left)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_examples() {
+        common::testing::examples(&DAY, Two);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn open_area() {
+        let input = "#.###\n#...#\n#...#\n#...#\n###.#\n";
+
+        assert_eq!(Puzzle::one(input), Ok("10".to_string()));
+        assert_eq!(Puzzle::two(input), Ok("10".to_string()));
+        assert!(Puzzle::one("#.###\n#.#.#\n###.#\n").is_err());
+    }
+
+    #[test]
+    fn too_many_junctions() {
+        let input = format!(
+            "#.{}\n{}{}.#\n",
+            "#".repeat(10),
+            "#..........#\n".repeat(10),
+            "#".repeat(10)
+        );
+
+        assert_eq!(
+            Puzzle::one(&input).map_err(|error| error.message),
+            Err("expected at most 64 junctions".to_string())
+        );
+    }
+
+    #[test]
+    fn visualize_example() {
+        let mut screen = Screen::recording();
+        visualize(EXAMPLE, Two, &mut screen).expect("example");
+        let frames = screen.finish();
+        let last = frames.last().expect("frames");
+
+        assert!(last.ends_with("steps: 154 of 154\n"));
+        assert_eq!(last.matches('O').count(), 154);
+    }
+}
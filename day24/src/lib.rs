@@ -0,0 +1,435 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Write};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use common::parse::{self, ParseError};
+use common::{Day, Example, Solution, Solver};
+
+use crate::Intersection::{Intersects, Overlaps, Parallel};
+
+const INPUT: Option<&str> = common::embedded_input!("input.txt");
+const EXAMPLE: &str = include_str!("example.txt");
+
+pub const DAY: Day = Day {
+    number: 24,
+    input: INPUT,
+    model: Puzzle::model,
+    visualize: None,
+    generate: None,
+    one: Solution {
+        solve: Puzzle::one,
+        examples: &[Example {
+            name: "example",
+            input: EXAMPLE,
+            solve: |input| Ok(collisions(&Storm::from_str(input)?, 7..=27).to_string()),
+        }],
+    },
+    two: Solution {
+        solve: Puzzle::two,
+        examples: &[],
+    },
+};
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    type Parsed = Storm;
+    type One = usize;
+    type Two = i64;
+
+    fn parse(input: &str) -> Result<Storm, ParseError> {
+        Storm::from_str(input)
+    }
+
+    fn part_one(storm: &Storm) -> Result<usize, ParseError> {
+        Ok(collisions(storm, 200000000000000..=400000000000000))
+    }
+
+    fn part_two(storm: &Storm) -> Result<i64, ParseError> {
+        let mut potential_x_velocity_set: Option<HashSet<i64>> = None;
+        let mut potential_y_velocity_set: Option<HashSet<i64>> = None;
+        let mut potential_z_velocity_set: Option<HashSet<i64>> = None;
+
+        let range = -1000..=1000;
+        let min_velocity = 50;
+
+        (0..storm.stones.len() - 1).for_each(|left_index| {
+            (left_index + 1..storm.stones.len()).for_each(|right_index| {
+                let left = &storm.stones[left_index];
+                let right = &storm.stones[right_index];
+
+                if left.velocity.x == right.velocity.x && left.velocity.x.abs() > min_velocity {
+                    let mut options: HashSet<i64> = HashSet::new();
+                    let difference = right.position.x - left.position.x;
+                    range.clone().for_each(|v| {
+                        if v != left.velocity.x && difference % (v - left.velocity.x) == 0 {
+                            options.insert(v);
+                        };
+                    });
+                    match &potential_x_velocity_set {
+                        None => potential_x_velocity_set = Some(options.clone()),
+                        Some(set) => {
+                            potential_x_velocity_set = Some(
+                                set.intersection(&options)
+                                    .copied()
+                                    .collect::<HashSet<i64>>(),
+                            )
+                        }
+                    }
+                }
+
+                if left.velocity.y == right.velocity.y && left.velocity.y.abs() > min_velocity {
+                    let mut options: HashSet<i64> = HashSet::new();
+                    let difference = right.position.y - left.position.y;
+                    range.clone().for_each(|v| {
+                        if v != left.velocity.y && difference % (v - left.velocity.y) == 0 {
+                            options.insert(v);
+                        };
+                    });
+                    match &potential_y_velocity_set {
+                        None => potential_y_velocity_set = Some(options.clone()),
+                        Some(set) => {
+                            potential_y_velocity_set = Some(
+                                set.intersection(&options)
+                                    .copied()
+                                    .collect::<HashSet<i64>>(),
+                            )
+                        }
+                    }
+                }
+
+                if left.velocity.z == right.velocity.z && left.velocity.z.abs() > min_velocity {
+                    let mut options: HashSet<i64> = HashSet::new();
+                    let difference = right.position.z - left.position.z;
+                    range.clone().for_each(|v| {
+                        if v != left.velocity.z && difference % (v - left.velocity.z) == 0 {
+                            options.insert(v);
+                        };
+                    });
+                    match &potential_z_velocity_set {
+                        None => potential_z_velocity_set = Some(options.clone()),
+                        Some(set) => {
+                            potential_z_velocity_set = Some(
+                                set.intersection(&options)
+                                    .copied()
+                                    .collect::<HashSet<i64>>(),
+                            )
+                        }
+                    }
+                }
+            })
+        });
+
+        let velocity = |set: Option<HashSet<i64>>| {
+            set.and_then(|set| set.into_iter().next()).ok_or_else(|| {
+                ParseError::unlocated("expected hailstones sharing a velocity on each axis")
+            })
+        };
+        let rvx = velocity(potential_x_velocity_set)?;
+        let rvy = velocity(potential_y_velocity_set)?;
+        let rvz = velocity(potential_z_velocity_set)?;
+
+        let (first, second) = match &storm.stones[..] {
+            [_, _, first, _, _, second, ..] => (first, second),
+            _ => return Err(ParseError::unlocated("expected at least six hailstones")),
+        };
+
+        let apx = first.position.x;
+        let apy = first.position.y;
+        let apz = first.position.z;
+        let avy = first.velocity.y;
+        let avx = first.velocity.x;
+        let avz = first.velocity.z;
+
+        let bpx = second.position.x;
+        let bpy = second.position.y;
+        let bvx = second.velocity.x;
+        let bvy = second.velocity.y;
+
+        let ma = (avy - rvy) as f64 / (avx - rvx) as f64;
+        let mb = (bvy - rvy) as f64 / (bvx - rvx) as f64;
+        let ca = apy as f64 - (ma * apx as f64);
+        let cb = bpy as f64 - (mb * bpx as f64);
+
+        let x = ((cb - ca) / (ma - mb)) as i64;
+        let y = (ma * x as f64 + ca) as i64;
+        let t = ((x as f64 - apx as f64) / (avx as f64 - rvx as f64)) as i64;
+        (avz - rvz)
+            .checked_mul(t)
+            .and_then(|z| z.checked_add(apz))
+            .and_then(|z| z.checked_add(x)?.checked_add(y))
+            .ok_or_else(|| ParseError::unlocated("expected the rock's position to fit in 64 bits"))
+    }
+}
+
+fn collisions(storm: &Storm, range: RangeInclusive<i64>) -> usize {
+    storm.collisions_in_x_y(range).len()
+}
+
+pub struct Storm {
+    stones: Vec<Stone>,
+}
+
+impl Storm {
+    fn collisions_in_x_y(&self, range: RangeInclusive<i64>) -> Vec<XyIntersection<'_>> {
+        (0..self.stones.len() - 1)
+            .flat_map(|left_index| {
+                (left_index + 1..self.stones.len())
+                    .filter_map(|right_index| {
+                        let left = &self.stones[left_index];
+                        let right = &self.stones[right_index];
+
+                        match left.collides_x_y(right) {
+                            Overlaps => Some(XyIntersection {
+                                points: [left, right],
+                            }), // TODO: check for lines outside the boix
+                            Parallel => None,
+                            Intersects(x) => match range.contains(&(x as i64)) {
+                                true => match range
+                                    .contains(&(LinearEquation::from(left).solve(x) as i64))
+                                {
+                                    true => Some(XyIntersection {
+                                        points: [left, right],
+                                    }),
+                                    false => None,
+                                },
+                                false => None,
+                            },
+                        }
+                    })
+                    .collect::<Vec<XyIntersection>>()
+            })
+            .collect::<Vec<XyIntersection>>()
+    }
+}
+
+impl FromStr for Storm {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let stones: Vec<Stone> = s.lines().map(Stone::from_str).collect::<Result<_, _>>()?;
+
+        match stones.len() {
+            0 | 1 => Err(ParseError::new(s, "expected at least two hailstones")),
+            _ => Ok(Self { stones }),
+        }
+    }
+}
+
+impl Display for Storm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.stones.iter().try_for_each(|stone| {
+            stone.fmt(f)?;
+            f.write_char('\n')
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Stone {
+    position: Point,
+    velocity: Velocity,
+}
+
+impl Stone {
+    fn collides_x_y(&self, other: &Stone) -> Intersection {
+        let self_equation = LinearEquation::from(self);
+        let other_equation = LinearEquation::from(other);
+
+        let intersection = self_equation.intersection(&other_equation);
+
+        match intersection {
+            Intersects(x) => match self.x_is_valid(x as i64) && other.x_is_valid(x as i64) {
+                true => intersection,
+                false => Parallel,
+            },
+            _ => intersection,
+        }
+    }
+
+    fn x_is_valid(&self, x: i64) -> bool {
+        match self.velocity.x.cmp(&0) {
+            Ordering::Less => x < self.position.x,
+            Ordering::Equal => x == self.position.x,
+            Ordering::Greater => x > self.position.x,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct XyIntersection<'a> {
+    points: [&'a Stone; 2],
+}
+
+impl<'a> Display for XyIntersection<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} - {}", self.points[0], self.points[1]))
+    }
+}
+
+impl FromStr for Stone {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = parse::split_once(s, " @ ")?;
+
+        Ok(Self {
+            position: Point::from_str(position)?,
+            velocity: Velocity::from_str(velocity)?,
+        })
+    }
+}
+
+impl Display for Stone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} @ {}", self.position, self.velocity))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let velocities: Vec<i64> = s
+            .split(", ")
+            .map(|speed| parse::number(speed.trim()))
+            .collect::<Result<_, _>>()?;
+
+        match velocities[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(ParseError::new(s, "expected x, y, z")),
+        }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}, {}, {}", self.x, self.y, self.z))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Velocity {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl FromStr for Velocity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let velocities: Vec<i64> = s
+            .split(", ")
+            .map(|speed| parse::number(speed.trim()))
+            .collect::<Result<_, _>>()?;
+
+        match velocities[..] {
+            [x, y, z] => Ok(Self { x, y, z }),
+            _ => Err(ParseError::new(s, "expected x, y, z")),
+        }
+    }
+}
+
+impl Display for Velocity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}, {}, {}", self.x, self.y, self.z))
+    }
+}
+
+enum Intersection {
+    Overlaps,
+    Parallel,
+    Intersects(f64),
+}
+
+impl Display for Intersection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Overlaps => f.write_str("overlaps"),
+            Parallel => f.write_str("parallel"),
+            Intersects(x) => f.write_fmt(format_args!("intersects at {}", x)),
+        }
+    }
+}
+
+struct LinearEquation {
+    a: f64,
+    b: f64,
+}
+
+impl LinearEquation {
+    fn solve(&self, x: f64) -> f64 {
+        self.a * x + self.b
+    }
+
+    fn intersection(&self, other: &LinearEquation) -> Intersection {
+        match (self.a == other.a, self.b == other.b) {
+            (true, true) => Overlaps,
+            (true, false) => Parallel,
+            (false, _) => Intersects((other.b - self.b) / (self.a - other.a)),
+        }
+    }
+}
+
+impl From<&Stone> for LinearEquation {
+    fn from(stone: &Stone) -> Self {
+        let a = stone.velocity.y as f64 / stone.velocity.x as f64;
+        let b = stone.position.y as f64 - a * stone.position.x as f64;
+
+        Self { a, b }
+    }
+}
+
+impl Display for LinearEquation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "y = {}x {}",
+            self.a,
+            match self.b < 0f64 {
+                true => format!("- {}", self.b.abs()),
+                false => format!("+ {}", self.b),
+            }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part::{One, Two};
+
+    use super::*;
+
+    #[test]
+    fn one_examples() {
+        common::testing::examples(&DAY, One);
+    }
+
+    #[test]
+    fn one_input() {
+        common::testing::input(&DAY, One);
+    }
+
+    #[test]
+    fn two_input() {
+        common::testing::input(&DAY, Two);
+    }
+
+    #[test]
+    fn two_without_shared_velocities() {
+        // the example's hailstones are too slow to tell the rock's velocity
+        assert_eq!(
+            Puzzle::two(EXAMPLE).map_err(|error| error.message),
+            Err("expected hailstones sharing a velocity on each axis".to_string())
+        );
+    }
+}
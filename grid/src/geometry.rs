@@ -0,0 +1,162 @@
+use std::ops::Add;
+
+use crate::geometry::Direction::{Down, Left, Right, Up};
+
+/// A point on a plane, with `x` going right and `y` going down, as on the puzzle maps.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// An integer type for the coordinates of a [Point].
+pub trait Coordinate: Copy + Ord + Add<Output = Self> {
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The absolute difference, in the same type.
+    fn distance(self, other: Self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($type:ty),*) => {$(
+        impl Coordinate for $type {
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+
+            fn distance(self, other: Self) -> Self {
+                self.max(other) - self.min(other)
+            }
+        }
+    )*};
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+impl<T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// The next point in a direction, unless the coordinate type can't hold it (e.g. left of 0).
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, T::ONE)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: T) -> Option<Self> {
+        match direction {
+            Up => Some(Self::new(self.x, self.y.checked_sub(distance)?)),
+            Down => Some(Self::new(self.x, self.y.checked_add(distance)?)),
+            Left => Some(Self::new(self.x.checked_sub(distance)?, self.y)),
+            Right => Some(Self::new(self.x.checked_add(distance)?, self.y)),
+        }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// A change of direction, relative to the current one.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+    Back,
+}
+
+impl Direction {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    pub fn turn(self, turn: Turn) -> Self {
+        let quarters = match turn {
+            Turn::Straight => 0,
+            Turn::Right => 1,
+            Turn::Back => 2,
+            Turn::Left => 3,
+        };
+
+        // ALL is in clockwise order, so turning right is the next one
+        Self::ALL[(self as usize + quarters) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(Turn::Back)
+    }
+
+    pub fn clockwise(self) -> Self {
+        self.turn(Turn::Right)
+    }
+
+    pub fn counter_clockwise(self) -> Self {
+        self.turn(Turn::Left)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Left | Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Up | Down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn() {
+        assert_eq!(Up.turn(Turn::Right), Right);
+        assert_eq!(Up.turn(Turn::Left), Left);
+        assert_eq!(Left.clockwise(), Up);
+        assert_eq!(Up.counter_clockwise(), Left);
+        assert_eq!(Down.turn(Turn::Straight), Down);
+        assert!(Direction::ALL
+            .iter()
+            .all(|direction| direction.opposite().opposite() == *direction
+                && direction.opposite() != *direction));
+    }
+
+    #[test]
+    fn step() {
+        let origin: Point<usize> = Point::new(0, 0);
+
+        assert_eq!(origin.step(Up), None);
+        assert_eq!(origin.step(Left), None);
+        assert_eq!(origin.step(Down), Some(Point::new(0, 1)));
+        assert_eq!(
+            Point::new(-3i64, 2).step_by(Left, 4),
+            Some(Point::new(-7, 2))
+        );
+        assert_eq!(Point::new(u32::MAX, 0).step(Right), None);
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(1u64, 6).manhattan_distance(Point::new(5, 2)), 8);
+        assert_eq!(
+            Point::new(-1i32, 2).manhattan_distance(Point::new(3, -2)),
+            8
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use common::visual::{Picture, Pixel};
+use common::ParseError;
+
+pub mod geometry;
+
+pub use geometry::{Direction, Point, Turn};
+
+/// A rectangular grid of cells, as most puzzle maps are, stored row by row.
+///
+/// Positions start at the top left, with `x` going right and `y` going down.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A position on a grid: column `x` and row `y`.
+pub type Position = Point<usize>;
+
+/// The eight neighbours, clockwise from up, as offsets.
+const ALL: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "{width}x{height} cells");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![cell; width * height])
+    }
+
+    /// Parses a grid with one character per cell. All lines must be equally long; a character for
+    /// which `cell` gives `None` is reported with the `expected` message.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = input
+            .lines()
+            .next()
+            .map(|line| line.chars().count())
+            .filter(|width| *width > 0)
+            .ok_or_else(|| ParseError::new(input, "expected a grid"))?;
+
+        let cells = input
+            .lines()
+            .map(|line| match line.chars().count() == width {
+                true => Ok(line),
+                false => Err(ParseError::new(line, format!("expected {width} columns"))),
+            })
+            .flat_map(|line| match line {
+                Ok(line) => line
+                    .char_indices()
+                    .map(|(index, char)| {
+                        cell(char).ok_or_else(|| {
+                            ParseError::new(&line[index..index + char.len_utf8()], expected)
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+                Err(error) => vec![Err(error)],
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        Ok(Self::new(width, cells.len() / width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[self.index_of(position)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => {
+                let index = self.index_of(position);
+                Some(&mut self.cells[index])
+            }
+            false => None,
+        }
+    }
+
+    /// The index of a position in the cells, row by row; handy for bit sets and other flat storage.
+    pub fn index_of(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        Position {
+            x: index % self.width,
+            y: index / self.width,
+        }
+    }
+
+    /// The next position in a direction, if it is still on the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// The position `dx` columns and `dy` rows away, if it is still on the grid.
+    pub fn offset(&self, position: Position, dx: isize, dy: isize) -> Option<Position> {
+        let moved = Position {
+            x: position.x.checked_add_signed(dx)?,
+            y: position.y.checked_add_signed(dy)?,
+        };
+
+        match self.contains(moved) {
+            true => Some(moved),
+            false => None,
+        }
+    }
+
+    /// The up to four horizontal and vertical neighbours on the grid, clockwise from up.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight neighbours on the grid, including the diagonal ones.
+    pub fn adjacent(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL.iter()
+            .filter_map(move |(dx, dy)| self.offset(position, *dx, *dy))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row by row, of a cell matching the predicate.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of a column, top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, cell: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(cell).collect(),
+        )
+    }
+
+    /// Like [Grid::map], for cells that depend on where they are.
+    pub fn map_with_position<U>(&self, cell: impl Fn(Position, &T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter()
+                .map(|(position, value)| cell(position, value))
+                .collect(),
+        )
+    }
+
+    /// Mirrors the grid in its diagonal: rows become columns.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(
+            self.height,
+            self.width,
+            self.columns().flat_map(|column| column.cloned()).collect(),
+        )
+    }
+
+    /// Rotates the grid a quarter turn clockwise: the top row becomes the right column.
+    pub fn rotated(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(
+            self.height,
+            self.width,
+            self.columns()
+                .flat_map(|column| column.rev().cloned())
+                .collect(),
+        )
+    }
+}
+
+impl Grid<Pixel> {
+    /// The grid as a frame for a visualization.
+    pub fn picture(&self, caption: impl Into<String>) -> Picture {
+        Picture {
+            rows: self.rows().map(|row| row.to_vec()).collect(),
+            caption: caption.into(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("position on the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).expect("position on the grid")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.rows().try_for_each(|row| {
+            row.iter()
+                .try_for_each(|cell| f.write_fmt(format_args!("{cell}")))?;
+            f.write_str("\n")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\n", "expected a letter", Some).expect("grid")
+    }
+
+    #[test]
+    fn parse() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position { x: 1, y: 1 }], 'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digit = |char: char| char.to_digit(10);
+
+        assert_eq!(
+            Grid::parse("12\n3x\n", "expected a digit", digit)
+                .expect_err("x")
+                .to_string(),
+            "expected a digit, found `x`"
+        );
+        assert_eq!(
+            Grid::parse("12\n345\n", "expected a digit", digit)
+                .expect_err("long line")
+                .to_string(),
+            "expected 2 columns, found `345`"
+        );
+        assert!(Grid::parse("", "expected a digit", digit).is_err());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example();
+        let corner = Position { x: 0, y: 0 };
+        let middle = Position { x: 1, y: 0 };
+
+        assert_eq!(
+            grid.neighbours(corner).map(|p| grid[p]).collect::<String>(),
+            "bd"
+        );
+        assert_eq!(
+            grid.adjacent(middle).map(|p| grid[p]).collect::<String>(),
+            "cfeda"
+        );
+        assert_eq!(grid.offset(corner, -1, 0), None);
+        assert_eq!(grid.offset(corner, 2, 1), Some(Position { x: 2, y: 1 }));
+        assert_eq!(grid.get(Position { x: 3, y: 0 }), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<String>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.find(|char| *char == 'e'),
+            Some(Position { x: 1, y: 1 })
+        );
+    }
+
+    #[test]
+    fn transposed_and_rotated() {
+        let grid = example();
+
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotated().to_string(), "da\neb\nfc\n");
+        assert_eq!(
+            grid.rotated().rotated().rotated().rotated().to_string(),
+            grid.to_string()
+        );
+    }
+}